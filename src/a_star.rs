@@ -2,11 +2,26 @@
 //! This the A* algorithme module.
 //! [For more explainations](https://xlinux.nist.gov/dads//HTML/manhattanDistance.html)
 
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
 pub use crate::graph::{Point, Field, get_start_to_end_points, get_start_to_end_points_multi_roads, get_element_childs_from_fs_aps, remove_end_point_from_aps};
+use crate::graph::get_element_childs_slice;
+use crate::bfs::get_index_road_from_parents as get_index_road_from_parents_list;
+pub use crate::stats::SearchStats;
+pub use crate::path::Path;
+pub use crate::limits::{SearchLimits, StopReason, LimitedSearch};
+pub use crate::flow_field::FlowField;
+pub use crate::cost::Cost;
+use crate::flow_field::flow_field_resolver;
+use crate::stats::record_road_search;
 
 /// Struct for handle A* algotithm interaction.
-/// It's compose of the target field, the parent field and the sum between the heuristic 
-/// and the cost to move on the target field.
+/// It's compose of the target field, the parent field, the sum between the heuristic 
+/// and the cost to move on the target field, and the cost from the start to the target field alone.
+/// The cost type is `usize` by default, it can be any type which implements `Cost`.
+/// In the open list of a search, a cost which cannot be represented by the type is `None`
+/// and the field is sorted after all the other fields.
 /// 
 /// # Example
 /// 
@@ -33,31 +48,42 @@ pub use crate::graph::{Point, Field, get_start_to_end_points, get_start_to_end_p
 ///             value: Some(1)
 ///     },
 ///     parent_field: None,
-///     move_cost: Some(9)
+///     move_cost: Some(9),
+///     g_cost: Some(0)
 /// 
 /// }));
 /// 
 /// a_star_example.move_cost = Some(10);
+/// a_star_example.g_cost = Some(1);
 /// ```
 #[derive(Debug, Clone, PartialEq)]
-pub struct AStarField {
+pub struct AStarField<C = usize> {
     pub wrapped_field: Field,
     pub parent_field: Option<Box<Self>>,
-    pub move_cost: Option<u8>
-    
+    pub move_cost: Option<C>,
+    pub g_cost: Option<C>
 }
 
-impl AStarField {
+impl<C: Cost> AStarField<C> {
     pub fn new() -> Self {
         return Self {
             wrapped_field: Field::new(),
             parent_field: None,
-            move_cost: None
+            move_cost: None,
+            g_cost: None
         };
     }
 }
 
+impl<C: Cost> Default for AStarField<C> {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
 /// Get heuristic value from start point to the target.
+/// It's 0 when a coordinate is missing.
+/// The distance is a `usize`, so the distance on a big bord has no overflow.
 /// 
 /// [For more explanations](https://xlinux.nist.gov/dads//HTML/manhattanDistance.html)
 /// 
@@ -78,20 +104,20 @@ impl AStarField {
 /// 
 /// assert_eq!(get_manhattan_distance_heuristic(start, end), 7);
 /// ```
-pub fn get_manhattan_distance_heuristic(start_coordinates: Point, end_coordinates: Point) -> u8 {
-    let x: i8 = start_coordinates.x.unwrap() as i8 - end_coordinates.x.unwrap() as i8;
-    let y: i8 = start_coordinates.y.unwrap() as i8 - end_coordinates.y.unwrap() as i8;
-
-    return (x.abs() + y.abs()) as u8;
+pub fn get_manhattan_distance_heuristic(start_coordinates: Point, end_coordinates: Point) -> usize {
+    return match (start_coordinates.x, start_coordinates.y, end_coordinates.x, end_coordinates.y) {
+        (Some(start_x), Some(start_y), Some(end_x), Some(end_y)) => start_x.abs_diff(end_x).saturating_add(start_y.abs_diff(end_y)),
+        _ => 0
+    };
 }
 
-pub fn a_star_multi_roads_resolver(fs: &mut Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Vec<Field>)) -> Result<Vec<Vec<Point>>, &'static str> {
+pub fn a_star_multi_roads_resolver(fs: &mut Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Vec<Field>)) -> Result<Vec<Path>, &'static str> {
     if fs.is_empty() || aps.is_empty() || matrix_size == 0 || start_end_point == (Field::new(), Vec::new()) {
         return Err("The parameters MUST be initializes");
     }
 
     let (start_point, end_points) = start_end_point;
-    let mut list_of_roads: Vec<Vec<Point>> = Vec::new();
+    let mut list_of_roads: Vec<Path> = Vec::new();
     
     for end_point in end_points {
         list_of_roads.push(a_star_resolver(fs.clone(), aps.clone(), matrix_size, (start_point, end_point))?); 
@@ -101,6 +127,225 @@ pub fn a_star_multi_roads_resolver(fs: &mut Vec<Field>, aps: Vec<u32>, matrix_si
     return Ok(list_of_roads);
 }
 
+/// Get the flow field to the end points of a multi roads bord.
+/// It's the alternative to `a_star_multi_roads_resolver` when many bots go to the same end points:
+/// the flow field is computed once, then each bot follows the moves of his field to the nearest end point.
+/// The start point MUST have a road to an end point.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::a_star::a_star_multi_roads_flow_field;
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![2, 0, 0],
+///     vec![-1, -1, 0],
+///     vec![1, 0, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(2), y: Some(0) },
+///         value: Some(1)
+///     },
+///     vec![Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(2)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(2), y: Some(2) },
+///         value: Some(2)
+///     }]
+/// );
+///
+/// let flow_field = a_star_multi_roads_flow_field(fs_example, aps_example, matrix_example.len(), start_end_fields.clone()).unwrap();
+///
+/// assert_eq!(flow_field.road_from(start_end_fields.0.coordinates).unwrap().points, vec![
+///     Point { x: Some(2), y: Some(0) },
+///     Point { x: Some(2), y: Some(1) },
+///     Point { x: Some(2), y: Some(2) }
+/// ]);
+/// assert_eq!(flow_field.distance(Point { x: Some(0), y: Some(2) }), Some(2));
+/// ```
+pub fn a_star_multi_roads_flow_field(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Vec<Field>)) -> Result<FlowField, &'static str> {
+    if fs.is_empty() || aps.is_empty() || matrix_size == 0 || start_end_point.0 == Field::new() || start_end_point.1.is_empty() {
+        return Err("The parameters MUST be initializes");
+    }
+
+    let (start_point, end_points) = start_end_point;
+    let flow_field = flow_field_resolver(fs, aps, matrix_size, end_points)?;
+
+    if flow_field.distance(start_point.coordinates).is_none() {
+        return Err("It seem that it has no end to this level");
+    }
+
+    return Ok(flow_field);
+}
+
+/// Get the roads to the nearest end points in a single A* search.
+/// The heuristic is the smaller manhattan distance to the end points which are not reached yet.
+/// The roads are ordered by distance from the start and their number is limited by `roads_number`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::a_star::a_star_nearest_roads_resolver;
+/// use esgi_arena_resolver_algorithms::path::Path;
+/// 
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![2, 0, 0],
+///     vec![-1, -1, 0],
+///     vec![1, 0, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(2), y: Some(0) },
+///         value: Some(1)
+///     },
+///     vec![Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(2)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(2), y: Some(2) },
+///         value: Some(2)
+///     }]
+/// );
+/// 
+/// assert_eq!(a_star_nearest_roads_resolver(fs_example, aps_example, matrix_example.len(), start_end_fields, 1).unwrap(), vec![Path::new(vec![
+///     Point { x: Some(2), y: Some(0) },
+///     Point { x: Some(2), y: Some(1) },
+///     Point { x: Some(2), y: Some(2) }
+/// ])]);
+/// ```
+pub fn a_star_nearest_roads_resolver(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Vec<Field>), roads_number: usize) -> Result<Vec<Path>, &'static str> {
+    if fs.is_empty() || aps.is_empty() || start_end_point.0 == Field::new() || start_end_point.1.is_empty() || roads_number == 0 {
+        return Err("The parameters MUST be initializes");
+    }
+
+    let (start_point, end_points) = start_end_point;
+    let fields_number = aps.len() - 1;
+    let start_index = start_point.coordinates.get_index(matrix_size)?;
+    let mut remaining_end_points: Vec<Point> = Vec::new();
+
+    for end_point in end_points {
+        if end_point.coordinates.get_index(matrix_size)? >= fields_number {
+            return Err("The target point cannot be found inside the matrix");
+        }
+
+        if ! remaining_end_points.contains(&end_point.coordinates) {
+            remaining_end_points.push(end_point.coordinates);
+        }
+    }
+
+    if start_index >= fields_number {
+        return Err("The target point cannot be found inside the matrix");
+    }
+
+    let nearest_end_heuristic = |coordinates: Point, remaining_end_points: &[Point]| -> u32 {
+        return remaining_end_points.iter()
+            .map(|end_coordinates| get_manhattan_distance_heuristic(coordinates, *end_coordinates) as u32)
+            .min()
+            .unwrap_or(0);
+    };
+
+    let mut weights: Vec<Option<u32>> = vec![None; fields_number];
+    let mut parents: Vec<Option<usize>> = vec![None; fields_number];
+    let mut closed: Vec<bool> = vec![false; fields_number];
+    let mut open_list: BinaryHeap<Reverse<(u32, usize)>> = BinaryHeap::new();
+    let mut roads: Vec<Path> = Vec::new();
+
+    weights[start_index] = Some(0);
+    open_list.push(Reverse((nearest_end_heuristic(start_point.coordinates, &remaining_end_points), start_index)));
+
+    while let Some(Reverse((cost, current_index))) = open_list.pop() {
+        if closed[current_index] {
+            continue;
+        }
+
+        let current_coordinates = Point::from_index(current_index, matrix_size)?;
+        let current_weight = weights[current_index].unwrap();
+        let current_cost = current_weight + nearest_end_heuristic(current_coordinates, &remaining_end_points);
+
+        // The heuristic grow when a end point is reached, so the cost inside the open list can be too small.
+        if current_cost > cost {
+            open_list.push(Reverse((current_cost, current_index)));
+            continue;
+        }
+
+        closed[current_index] = true;
+
+        if let Some(end_point_position) = remaining_end_points.iter().position(|end_coordinates| *end_coordinates == current_coordinates) {
+            roads.push(Path::new(get_index_road_from_parents_list(&parents, matrix_size, current_index)?));
+            remaining_end_points.remove(end_point_position);
+
+            if roads.len() == roads_number || remaining_end_points.is_empty() {
+                break;
+            }
+        }
+
+        for child in get_element_childs_slice(&fs, &aps, current_index)? {
+            if child.value == Some(-1) {
+                continue;
+            }
+
+            let child_index = child.coordinates.get_index(matrix_size)?;
+            let child_weight = current_weight + 1;
+
+            if child_index >= fields_number || closed[child_index] || weights[child_index].is_some_and(|weight| weight <= child_weight) {
+                continue;
+            }
+
+            weights[child_index] = Some(child_weight);
+            parents[child_index] = Some(current_index);
+            open_list.push(Reverse((child_weight + nearest_end_heuristic(child.coordinates, &remaining_end_points), child_index)));
+        }
+    }
+
+    if roads.is_empty() {
+        return Err("It seem that it has no end to this level");
+    }
+
+    return Ok(roads);
+}
+
+/// Get the road to the nearest end point in a single A* search.
+/// 
+/// # Example
+/// 
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::a_star::a_star_nearest_road_resolver;
+/// 
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![2, 0, 0],
+///     vec![0, 0, 0],
+///     vec![1, 0, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(2), y: Some(0) },
+///         value: Some(1)
+///     },
+///     vec![Field {
+///         coordinates: Point { x: Some(2), y: Some(2) },
+///         value: Some(2)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(2)
+///     }]
+/// );
+/// 
+/// assert_eq!(a_star_nearest_road_resolver(fs_example, aps_example, matrix_example.len(), start_end_fields).unwrap().len(), 3);
+/// ```
+pub fn a_star_nearest_road_resolver(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Vec<Field>)) -> Result<Path, &'static str> {
+    let mut roads = a_star_nearest_roads_resolver(fs, aps, matrix_size, start_end_point, 1)?;
+
+    return Ok(roads.remove(0));
+}
+
 /// A* resolver function.
 /// It's find the shorter path between two points of a graph.
 /// 
@@ -116,7 +361,7 @@ pub fn a_star_multi_roads_resolver(fs: &mut Vec<Field>, aps: Vec<u32>, matrix_si
 /// vec![0, 1], 
 /// vec![0, 2]
 /// ];
-
+///
 /// let fs_example: Vec<Field> = vec![
 /// Field {
 ///     coordinates: Point {
@@ -175,7 +420,7 @@ pub fn a_star_multi_roads_resolver(fs: &mut Vec<Field>, aps: Vec<u32>, matrix_si
 ///     value: Some(1)
 /// }
 /// ];
-
+///
 /// let aps_example: Vec<u32> = vec![0, 2, 4, 6, 8];
 /// let start_end_fields = (Field {
 ///     coordinates: Point {
@@ -193,7 +438,7 @@ pub fn a_star_multi_roads_resolver(fs: &mut Vec<Field>, aps: Vec<u32>, matrix_si
 ///     }
 /// );
 /// 
-/// assert_eq!(a_star_resolver(fs_example, aps_example, matrix_example.len(), start_end_fields).unwrap(), vec![
+/// assert_eq!(a_star_resolver(fs_example, aps_example, matrix_example.len(), start_end_fields).unwrap().points, vec![
 ///     Point {
 ///         x: Some(0),
 ///         y: Some(1)
@@ -204,79 +449,565 @@ pub fn a_star_multi_roads_resolver(fs: &mut Vec<Field>, aps: Vec<u32>, matrix_si
 ///     }
 /// ]);
 /// ```
-pub fn a_star_resolver(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field)) -> Result<Vec<Point>, &'static str> {
-    if fs.is_empty() || aps.is_empty() || start_end_point == (Field::new(), Field::new()) {
-        return Err("The parameters MUST be initializes");
-    }
+pub fn a_star_resolver(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field)) -> Result<Path, &'static str> {
+    return a_star_resolver_with_stats(fs, aps, matrix_size, start_end_point, &mut SearchStats::new());
+}
 
-    let (start_point, end_point) = start_end_point;
+/// The same search as the `a_star_resolver` function which also fill the search statistics.
+/// 
+/// # Example
+/// 
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::a_star::{ a_star_resolver_with_stats, SearchStats };
+/// 
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![0, 1],
+///     vec![-1, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(0), y: Some(1) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(1), y: Some(1) },
+///         value: Some(2)
+///     }
+/// );
+/// let mut stats = SearchStats::new();
+/// 
+/// a_star_resolver_with_stats(fs_example, aps_example, matrix_example.len(), start_end_fields, &mut stats).unwrap();
+/// 
+/// assert_eq!(stats.path_cost, Some(1));
+/// assert_eq!(stats.nodes_expanded, 2);
+/// assert_eq!(stats.nodes_generated, 2);
+/// ```
+pub fn a_star_resolver_with_stats(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), stats: &mut SearchStats) -> Result<Path, &'static str> {
+    return record_road_search(stats, |stats| a_star_search(fs, aps, matrix_size, start_end_point, stats));
+}
+
+fn a_star_search(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), stats: &mut SearchStats) -> Result<Path, &'static str> {
+    let mut search = AStarSearch::new(fs, aps, matrix_size, start_end_point)?;
 
-    let start_field = AStarField {
-        wrapped_field: start_point,
-        parent_field: None,
-        move_cost: Some(get_manhattan_distance_heuristic(start_point.coordinates, end_point.coordinates))
+    search.by_ref().for_each(drop);
+    *stats = search.stats();
+
+    return search.outcome().unwrap_or(Err("It seem that it has no end to this level"));
+}
+
+/// The same search as the `a_star_resolver` function where every move costs the same `move_cost`.
+/// The bord has no weight on his fields, so the road is the same as the `a_star_resolver` road:
+/// `move_cost` is only a scale of the costs, in his type, integer of any width or float.
+/// It's return the road and his cost, the number of moves multiplied by `move_cost`.
+/// Only a uniform cost is supported: a bord where the fields have different weights cannot be resolved by this function.
+/// The search return a error when the cost of a expanded field or of the road cannot be represented by the type,
+/// a field generated with a too big cost but never expanded does not stop the search.
+/// 
+/// # Example
+/// 
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::a_star::a_star_uniform_cost_resolver;
+/// 
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![2, 0, 0],
+///     vec![-1, -1, 0],
+///     vec![1, 0, 0]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(2), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(2)
+///     }
+/// );
+/// 
+/// let (road, cost) = a_star_uniform_cost_resolver(fs_example.clone(), aps_example.clone(), matrix_example.len(), start_end_fields, 2.5_f64).unwrap();
+/// 
+/// assert_eq!(road.len(), 7);
+/// assert_eq!(cost, 15.0);
+/// assert_eq!(a_star_uniform_cost_resolver(fs_example.clone(), aps_example.clone(), matrix_example.len(), start_end_fields, 1000_u32).unwrap().1, 6000);
+/// assert!(a_star_uniform_cost_resolver(fs_example, aps_example, matrix_example.len(), start_end_fields, 50_u8).is_err());
+/// ```
+pub fn a_star_uniform_cost_resolver<C: Cost>(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), move_cost: C) -> Result<(Path, C), &'static str> {
+    let mut search = AStarSearch::with_move_cost(fs, aps, matrix_size, start_end_point, move_cost)?;
+
+    search.by_ref().for_each(drop);
+
+    let road = search.outcome().unwrap_or(Err("It seem that it has no end to this level"))?;
+    let road_cost = move_cost.checked_mul_distance(road.cost).ok_or("The road cost is too big")?;
+
+    return Ok((road, road_cost));
+}
+
+/// The same search as the `a_star_resolver` function which stop when a limit is reached.
+/// A stopped search return the road to the expanded field which is the closest to the end, marked as incomplete.
+/// When `max_depth` is reached the end can still be found by a longer road,
+/// so the search is also marked as incomplete when no road is found.
+/// 
+/// # Example
+/// 
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::a_star::{ a_star_resolver_with_limits, SearchLimits, StopReason };
+/// 
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![2, 0, 0],
+///     vec![-1, -1, 0],
+///     vec![1, 0, 0]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(2), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(2)
+///     }
+/// );
+/// let mut limits = SearchLimits::new();
+/// 
+/// limits.max_expansions = Some(3);
+/// 
+/// let limited_search = a_star_resolver_with_limits(fs_example, aps_example, matrix_example.len(), start_end_fields, &limits).unwrap();
+/// 
+/// assert!(! limited_search.is_complete);
+/// assert_eq!(limited_search.stop_reason, Some(StopReason::MaxExpansions));
+/// assert_eq!(limited_search.result.first(), Some(&start_end_fields.0.coordinates));
+/// ```
+pub fn a_star_resolver_with_limits(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), limits: &SearchLimits) -> Result<LimitedSearch<Path>, &'static str> {
+    let mut search = AStarSearch::new(fs, aps, matrix_size, start_end_point)?;
+
+    search.max_depth = limits.max_depth;
+
+    loop {
+        if let Some(stop_reason) = limits.stop_reason(search.stats().nodes_expanded, 0) {
+            return Ok(LimitedSearch::incomplete(search.road_to_closest_field(), stop_reason));
+        }
+
+        if search.next().is_none() {
+            break;
+        }
+    }
+
+    return match search.outcome() {
+        Some(Ok(road)) => Ok(LimitedSearch::complete(road)),
+        _ if search.is_depth_pruned => Ok(LimitedSearch::incomplete(search.road_to_closest_field(), StopReason::MaxDepth)),
+        Some(Err(message)) => Err(message),
+        None => Err("It seem that it has no end to this level")
     };
+}
 
-    let mut open_list: Vec<AStarField> = vec![start_field];
-    let mut close_list: Vec<AStarField> = Vec::new();
-    let mut weight: u8 = 1;
+/// One expansion of a A* search.
+/// `g_cost` is the cost from the start to the current field, `h_cost` is the heuristic to the end
+/// and `f_cost` is the sum of them.
+/// The open and closed fields are the lists after the expansion.
+/// The road is only given by the expansion of the end field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AStarStep<C = usize> {
+    pub current_field: Field,
+    pub g_cost: C,
+    pub h_cost: C,
+    pub f_cost: C,
+    pub open_fields: Vec<AStarField<C>>,
+    pub closed_fields: Vec<Field>,
+    pub road: Option<Path>
+}
+
+/// Step by step A* search.
+/// Each call to `next` expand one field and return a `AStarStep`,
+/// the iterator stop after the end field is expanded or when the open list is empty.
+/// The final road is the same as the `a_star_resolver` one.
+/// 
+/// # Example
+/// 
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::a_star::{ AStarSearch, a_star_resolver };
+/// 
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![2, 0, 0],
+///     vec![-1, -1, 0],
+///     vec![1, 0, 0]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(2), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(2)
+///     }
+/// );
+/// 
+/// let mut search = AStarSearch::new(fs_example.clone(), aps_example.clone(), matrix_example.len(), start_end_fields).unwrap();
+/// let first_step = search.next().unwrap();
+/// 
+/// assert_eq!(first_step.current_field, start_end_fields.0);
+/// assert_eq!((first_step.g_cost, first_step.h_cost, first_step.f_cost), (0, 2, 2));
+/// assert_eq!(first_step.open_fields.len(), 1);
+/// 
+/// let last_step = search.last().unwrap();
+/// 
+/// assert_eq!(last_step.road, Some(a_star_resolver(fs_example, aps_example, matrix_example.len(), start_end_fields).unwrap()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct AStarSearch<C = usize> {
+    fs: Vec<Field>,
+    aps: Vec<u32>,
+    matrix_size: usize,
+    start_point: Field,
+    end_point: Field,
+    move_cost: C,
+    open_list: Vec<AStarField<C>>,
+    close_list: Vec<AStarField<C>>,
+    stats: SearchStats,
+    outcome: Option<Result<Path, &'static str>>,
+    max_depth: Option<usize>,
+    is_depth_pruned: bool
+}
+
+impl AStarSearch {
+    pub fn new(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field)) -> Result<Self, &'static str> {
+        return Self::with_move_cost(fs, aps, matrix_size, start_end_point, 1);
+    }
+}
+
+impl<C: Cost> AStarSearch<C> {
+    /// Create a search where each move costs `move_cost`, the costs of the steps are of the same type.
+    pub fn with_move_cost(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), move_cost: C) -> Result<Self, &'static str> {
+        if fs.is_empty() || aps.is_empty() || start_end_point == (Field::new(), Field::new()) {
+            return Err("The parameters MUST be initializes");
+        }
+
+        if move_cost.partial_cmp(&C::zero()) != Some(Ordering::Greater) {
+            return Err("The move cost MUST be bigger than 0");
+        }
+
+        let (start_point, end_point) = start_end_point;
+        let fields_number = aps.len() - 1;
+
+        if start_point.coordinates.get_index(matrix_size)? >= fields_number || end_point.coordinates.get_index(matrix_size)? >= fields_number {
+            return Err("The target point cannot be found inside the matrix");
+        }
+
+        let start_field = AStarField {
+            wrapped_field: start_point,
+            parent_field: None,
+            move_cost: Some(get_cost_heuristic(start_point.coordinates, end_point.coordinates, move_cost)?),
+            g_cost: Some(C::zero())
+        };
+        let mut stats = SearchStats::new();
+
+        stats.update_open_list_size(1);
+
+        return Ok(Self {
+            fs,
+            aps,
+            matrix_size,
+            start_point,
+            end_point,
+            move_cost,
+            open_list: vec![start_field],
+            close_list: Vec::new(),
+            stats,
+            outcome: None,
+            max_depth: None,
+            is_depth_pruned: false
+        });
+    }
+
+    /// The fields waiting to be expanded.
+    pub fn open_list(&self) -> &[AStarField<C>] {
+        return &self.open_list;
+    }
+
+    /// The fields already expanded.
+    pub fn close_list(&self) -> &[AStarField<C>] {
+        return &self.close_list;
+    }
+
+    /// The statistics of the search until now.
+    pub fn stats(&self) -> SearchStats {
+        return self.stats;
+    }
 
-    while ! open_list.is_empty() {
-        quicksort(&mut open_list[..]);
-        open_list.reverse();
+    /// The result of the search, `None` while the search is not finished.
+    pub fn outcome(&self) -> Option<Result<Path, &'static str>> {
+        return self.outcome.clone();
+    }
+
+    /// Get the road to the expanded field which is the closest to the end.
+    pub fn road_to_closest_field(&self) -> Path {
+        let closest_field = self.close_list.iter()
+            .min_by_key(|closed_field| get_manhattan_distance_heuristic(closed_field.wrapped_field.coordinates, self.end_point.coordinates));
+
+        return match closest_field {
+            Some(closest_field) => Path::new(get_index_road_from_parents(closest_field.clone()).unwrap_or_else(|| vec![closest_field.wrapped_field.coordinates])),
+            None => Path::new(vec![self.start_point.coordinates])
+        };
+    }
+
+    fn expand_next_field(&mut self) -> Result<Option<AStarStep<C>>, &'static str> {
+        if self.open_list.is_empty() {
+            return Err("It seem that it has no end to this level");
+        }
+
+        quicksort(&mut self.open_list[..]);
+        self.open_list.reverse();
+
+        let current_a_star_field = self.open_list.pop().unwrap();
+        self.stats.nodes_expanded += 1;
+        self.close_list.push(current_a_star_field.clone());
 
-        let current_a_star_field = open_list.pop();
-        close_list.push(current_a_star_field.clone().unwrap());
+        let current_field = current_a_star_field.wrapped_field;
+        let f_cost = current_a_star_field.move_cost.ok_or("The road cost is too big")?;
+        let g_cost = current_a_star_field.g_cost.ok_or("The road cost is too big")?;
+        let h_cost = get_cost_heuristic(current_field.coordinates, self.end_point.coordinates, self.move_cost)?;
+        let is_too_deep = self.max_depth.is_some_and(|max_depth| get_road_depth(&current_a_star_field) + 1 > max_depth);
 
-        if current_a_star_field.clone().unwrap().wrapped_field.value.unwrap() == 2 {
-            return Ok(get_index_road_from_parents(current_a_star_field.unwrap()).unwrap());
+        if current_field.value == Some(2) {
+            let road = Path::new(get_index_road_from_parents(current_a_star_field).unwrap_or_else(|| vec![current_field.coordinates]));
+            self.outcome = Some(Ok(road.clone()));
+
+            return Ok(Some(self.step(current_field, (g_cost, h_cost, f_cost), Some(road))));
         }
 
-        let current_a_star_field_index = current_a_star_field.clone().unwrap().wrapped_field.coordinates.get_index(matrix_size);
-        let current_a_star_field_childs: Vec<Field> = get_element_childs_from_fs_aps(fs.clone(), aps.clone(), current_a_star_field_index?)?;
+        let current_a_star_field_index = current_field.coordinates.get_index(self.matrix_size)?;
+        let current_a_star_field_childs: Vec<Field> = get_element_childs_from_fs_aps(self.fs.clone(), self.aps.clone(), current_a_star_field_index)?;
 
         for child in current_a_star_field_childs {
             let mut is_invalid_son = false;
 
-            if child.value.unwrap() == -1 {
+            if child.value == Some(-1) {
                 is_invalid_son = true;
             }
 
-            for closed_field in close_list.clone() {
+            for closed_field in self.close_list.iter() {
                 if closed_field.wrapped_field == child {
                     is_invalid_son = true;
                 }
             }
 
-            let a_star_child = AStarField {
-                wrapped_field: child.clone(),
-                move_cost: Some(weight + get_manhattan_distance_heuristic(child.clone().coordinates, end_point.coordinates)),
-                parent_field: Some(Box::new(current_a_star_field.clone().unwrap()))
+            if is_invalid_son {
+                continue;
+            }
+
+            if is_too_deep {
+                self.is_depth_pruned = true;
+                continue;
+            }
 
+            // A cost too big for the type only fails the search when the child is expanded,
+            // the road to the end can still be cheaper.
+            let child_g_cost = g_cost.checked_add(self.move_cost);
+            let child_move_cost = child_g_cost.and_then(|child_g_cost| {
+                return get_cost_heuristic(child.coordinates, self.end_point.coordinates, self.move_cost).ok()
+                    .and_then(|child_h_cost| child_g_cost.checked_add(child_h_cost));
+            });
+            let a_star_child = AStarField {
+                wrapped_field: child,
+                move_cost: child_move_cost,
+                g_cost: child_g_cost,
+                parent_field: Some(Box::new(current_a_star_field.clone()))
             };
 
-            for opened_field in open_list.clone() {
+            let mut is_reopened = false;
+
+            for opened_field in self.open_list.iter() {
                 if opened_field.wrapped_field == child 
-                    && a_star_child.move_cost.unwrap() < opened_field.move_cost.unwrap(){
+                    && is_cost_lower_or_equal(opened_field.move_cost, a_star_child.move_cost) {
                     is_invalid_son = true;
                 }
+
+                if opened_field.wrapped_field == child {
+                    is_reopened = true;
+                }
             }
 
             if is_invalid_son {
                 continue;
             }
 
-            open_list.push(a_star_child);
+            if is_reopened {
+                self.stats.reopened_nodes += 1;
+            }
+
+            self.open_list.push(a_star_child);
+            self.stats.nodes_generated += 1;
         }
 
-        weight += 1;
+        self.stats.update_open_list_size(self.open_list.len());
 
+        return Ok(Some(self.step(current_field, (g_cost, h_cost, f_cost), None)));
+    }
+
+    fn step(&self, current_field: Field, (g_cost, h_cost, f_cost): (C, C, C), road: Option<Path>) -> AStarStep<C> {
+        return AStarStep {
+            current_field,
+            g_cost,
+            h_cost,
+            f_cost,
+            open_fields: self.open_list.clone(),
+            closed_fields: self.close_list.iter().map(|closed_field| closed_field.wrapped_field).collect(),
+            road
+        };
+    }
+}
+
+impl<C: Cost> Iterator for AStarSearch<C> {
+    type Item = AStarStep<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.outcome.is_some() {
+            return None;
+        }
+
+        return match self.expand_next_field() {
+            Ok(step) => step,
+            Err(message) => {
+                self.outcome = Some(Err(message));
+                None
+            }
+        };
     }
-    
-    return Err("It seem that it has no end to this level");
 }
 
-fn quicksort(to_sort: &mut [AStarField]) {
+/// Bidirectional A* resolver function.
+/// It's run a A* from the start to the end and a A* from the end to the start at the same time.
+/// The search stop when the best road found is not longer than the smallest cost
+/// of one of the two open lists, so the road has the same cost as the `a_star_resolver` one.
+/// 
+/// [For more explainations](https://en.wikipedia.org/wiki/Bidirectional_search)
+/// 
+/// # Example
+/// 
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::a_star::bidirectional_a_star_resolver;
+/// 
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![2, 0, 0],
+///     vec![-1, -1, 0],
+///     vec![1, 0, 0]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(2), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(2)
+///     }
+/// );
+/// 
+/// assert_eq!(bidirectional_a_star_resolver(fs_example, aps_example, matrix_example.len(), start_end_fields).unwrap().len(), 7);
+/// ```
+pub fn bidirectional_a_star_resolver(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field)) -> Result<Path, &'static str> {
+    if fs.is_empty() || aps.is_empty() || start_end_point == (Field::new(), Field::new()) {
+        return Err("The parameters MUST be initializes");
+    }
+
+    let (start_point, end_point) = start_end_point;
+    let fields_number = aps.len() - 1;
+    let start_index = start_point.coordinates.get_index(matrix_size)?;
+    let end_index = end_point.coordinates.get_index(matrix_size)?;
+
+    if start_index >= fields_number || end_index >= fields_number {
+        return Err("The target point cannot be found inside the matrix");
+    }
+
+    if start_index == end_index {
+        return Ok(Path::new(vec![start_point.coordinates]));
+    }
+
+    // The index 0 is the search from the start and the index 1 the search from the end.
+    let targets = [end_point.coordinates, start_point.coordinates];
+    let mut weights: [Vec<Option<u32>>; 2] = [vec![None; fields_number], vec![None; fields_number]];
+    let mut parents: [Vec<Option<usize>>; 2] = [vec![None; fields_number], vec![None; fields_number]];
+    let mut closed: [Vec<bool>; 2] = [vec![false; fields_number], vec![false; fields_number]];
+    let mut open_lists: [BinaryHeap<Reverse<(u32, usize)>>; 2] = [BinaryHeap::new(), BinaryHeap::new()];
+    let mut best_road: Option<(u32, usize)> = None;
+
+    weights[0][start_index] = Some(0);
+    weights[1][end_index] = Some(0);
+    open_lists[0].push(Reverse((get_manhattan_distance_heuristic(start_point.coordinates, targets[0]) as u32, start_index)));
+    open_lists[1].push(Reverse((get_manhattan_distance_heuristic(end_point.coordinates, targets[1]) as u32, end_index)));
+
+    loop {
+        for direction in 0..2 {
+            while let Some(Reverse((_, index))) = open_lists[direction].peek() {
+                if ! closed[direction][*index] {
+                    break;
+                }
+
+                open_lists[direction].pop();
+            }
+        }
+
+        let (forward_min_cost, backward_min_cost) = match (open_lists[0].peek(), open_lists[1].peek()) {
+            (Some(Reverse((forward_cost, _))), Some(Reverse((backward_cost, _)))) => (*forward_cost, *backward_cost),
+            _ => break
+        };
+
+        if let Some((best_cost, _)) = best_road {
+            if best_cost <= forward_min_cost.max(backward_min_cost) {
+                break;
+            }
+        }
+
+        let direction = if open_lists[0].len() <= open_lists[1].len() { 0 } else { 1 };
+        let Reverse((_, current_index)) = open_lists[direction].pop().unwrap();
+        let current_weight = weights[direction][current_index].unwrap();
+
+        closed[direction][current_index] = true;
+
+        for child in get_element_childs_slice(&fs, &aps, current_index)? {
+            if child.value == Some(-1) {
+                continue;
+            }
+
+            let child_index = child.coordinates.get_index(matrix_size)?;
+            let child_weight = current_weight + 1;
+
+            if child_index >= fields_number || closed[direction][child_index] {
+                continue;
+            }
+
+            if weights[direction][child_index].is_none_or(|weight| child_weight < weight) {
+                weights[direction][child_index] = Some(child_weight);
+                parents[direction][child_index] = Some(current_index);
+                open_lists[direction].push(Reverse((child_weight + get_manhattan_distance_heuristic(child.coordinates, targets[direction]) as u32, child_index)));
+            }
+
+            if let Some(other_weight) = weights[1 - direction][child_index] {
+                let road_cost = weights[direction][child_index].unwrap() + other_weight;
+
+                if best_road.is_none_or(|(best_cost, _)| road_cost < best_cost) {
+                    best_road = Some((road_cost, child_index));
+                }
+            }
+        }
+    }
+
+    let (_, meeting_index) = best_road.ok_or("It seem that it has no end to this level")?;
+    let mut start_to_end_road = get_index_road_from_parents_list(&parents[0], matrix_size, meeting_index)?;
+    let mut meeting_to_end_road = get_index_road_from_parents_list(&parents[1], matrix_size, meeting_index)?;
+
+    meeting_to_end_road.reverse();
+    start_to_end_road.extend(meeting_to_end_road.into_iter().skip(1));
+
+    return Ok(Path::new(start_to_end_road));
+}
+
+fn quicksort<C: Cost>(to_sort: &mut [AStarField<C>]) {
     if ! to_sort.is_empty() {
         let partition_index = quicksort_partition(to_sort);
         let to_sort_lenght = to_sort.len();
@@ -286,12 +1017,12 @@ fn quicksort(to_sort: &mut [AStarField]) {
     }
 }
 
-fn quicksort_partition(to_partition: &mut [AStarField]) -> usize {
-    let pivot = to_partition[to_partition.len() - 1].move_cost.unwrap();
+fn quicksort_partition<C: Cost>(to_partition: &mut [AStarField<C>]) -> usize {
+    let pivot = to_partition[to_partition.len() - 1].move_cost;
     let mut x = 0;
 
     for i in 0..to_partition.len() - 1 {
-        if to_partition[i].move_cost.unwrap() <= pivot {
+        if is_cost_lower_or_equal(to_partition[i].move_cost, pivot) {
             to_partition.swap(x, i);
             x += 1;
         }
@@ -302,11 +1033,36 @@ fn quicksort_partition(to_partition: &mut [AStarField]) -> usize {
     return x;
 }
 
-fn get_index_road_from_parents(mut final_a_star_field: AStarField) -> Option<Vec<Point>> {
-    if final_a_star_field.parent_field.is_none() {
-        return None;
+/// Compare two costs of the open list, a `None` cost is too big for the type so it's bigger than all the others.
+fn is_cost_lower_or_equal<C: Cost>(first_cost: Option<C>, second_cost: Option<C>) -> bool {
+    return match (first_cost, second_cost) {
+        (Some(first_cost), Some(second_cost)) => first_cost <= second_cost,
+        (_, None) => true,
+        (None, Some(_)) => false
+    };
+}
+
+/// Get the heuristic of a field as a cost, each move of the Manhattan distance costs `move_cost`.
+fn get_cost_heuristic<C: Cost>(start_coordinates: Point, end_coordinates: Point, move_cost: C) -> Result<C, &'static str> {
+    return move_cost.checked_mul_distance(get_manhattan_distance_heuristic(start_coordinates, end_coordinates)).ok_or("The road cost is too big");
+}
+
+/// Get the number of moves from the start to a field.
+fn get_road_depth<C>(a_star_field: &AStarField<C>) -> usize {
+    let mut depth = 0;
+    let mut current_field = a_star_field;
+
+    while let Some(parent_field) = current_field.parent_field.as_ref() {
+        depth += 1;
+        current_field = parent_field;
     }
 
+    return depth;
+}
+
+fn get_index_road_from_parents<C>(mut final_a_star_field: AStarField<C>) -> Option<Vec<Point>> {
+    final_a_star_field.parent_field.as_ref()?;
+
     let mut start_to_end_road: Vec<Point> = Vec::new();
     
     while final_a_star_field.parent_field.is_some() {
@@ -328,16 +1084,16 @@ mod tests {
     #[test]
     fn quicksort_test() {
         let mut sample_data: Vec<AStarField> = vec![
-            AStarField { wrapped_field: Field::new(), parent_field: None, move_cost: Some(10) },
-            AStarField { wrapped_field: Field::new(), parent_field: None, move_cost: Some(11) },
-            AStarField { wrapped_field: Field::new(), parent_field: None, move_cost: Some(9) },
-            AStarField { wrapped_field: Field::new(), parent_field: None, move_cost: Some(15) }
+            AStarField { wrapped_field: Field::new(), parent_field: None, move_cost: Some(10), g_cost: None },
+            AStarField { wrapped_field: Field::new(), parent_field: None, move_cost: Some(11), g_cost: None },
+            AStarField { wrapped_field: Field::new(), parent_field: None, move_cost: Some(9), g_cost: None },
+            AStarField { wrapped_field: Field::new(), parent_field: None, move_cost: Some(15), g_cost: None }
         ];
         let expected_output = vec![
-            AStarField { wrapped_field: Field::new(), parent_field: None, move_cost: Some(9) },
-            AStarField { wrapped_field: Field::new(), parent_field: None, move_cost: Some(10) },
-            AStarField { wrapped_field: Field::new(), parent_field: None, move_cost: Some(11) },
-            AStarField { wrapped_field: Field::new(), parent_field: None, move_cost: Some(15) }
+            AStarField { wrapped_field: Field::new(), parent_field: None, move_cost: Some(9), g_cost: None },
+            AStarField { wrapped_field: Field::new(), parent_field: None, move_cost: Some(10), g_cost: None },
+            AStarField { wrapped_field: Field::new(), parent_field: None, move_cost: Some(11), g_cost: None },
+            AStarField { wrapped_field: Field::new(), parent_field: None, move_cost: Some(15), g_cost: None }
         ];
         quicksort(&mut sample_data[..]);
 
@@ -346,30 +1102,138 @@ mod tests {
 
     #[test]
     fn get_parents_list_test() {
-        let start_element = Box::new(AStarField { wrapped_field: Field { 
+        let start_element: Box<AStarField> = Box::new(AStarField { wrapped_field: Field { 
                 coordinates: Point { x: Some(0), y: Some(1) }, 
                 value: Some(1) 
             }, 
             parent_field: None, 
-            move_cost: None 
+            move_cost: None,
+            g_cost: None
         });
         let road_element = Box::new(AStarField { wrapped_field: Field { 
                 coordinates: Point { x: Some(0), y: Some(0) }, 
                 value: Some(1) 
             }, 
             parent_field: Some(start_element), 
-            move_cost: None 
+            move_cost: None,
+            g_cost: None
         });
         let road_end = Box::new(AStarField { wrapped_field: Field { 
                 coordinates: Point { x: Some(1), y: Some(1) }, 
                 value: Some(1) 
             }, 
             parent_field: Some(road_element), 
-            move_cost: None 
+            move_cost: None,
+            g_cost: None
         });
 
         let expected_output: Vec<Point> = vec![Point { x: Some(0), y: Some(1) }, Point { x: Some(0), y: Some(0) }, Point { x: Some(1), y: Some(1) }];
 
         assert_eq!(get_index_road_from_parents(*road_end).unwrap(), expected_output);
     }
+
+    #[test]
+    fn a_star_uniform_cost_resolver_long_road_test() {
+        let mut matrix: Vec<Vec<i8>> = (0..20).map(|line| (0..20).map(|column| if line % 2 == 1 && column != if line % 4 == 1 { 19 } else { 0 } { -1 } else { 0 }).collect()).collect();
+
+        matrix[0][0] = 1;
+        matrix[18][0] = 2;
+
+        let (fs, aps) = crate::graph::fs_aps_from_matrix(matrix).unwrap();
+        let start_end = (
+            Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(1) },
+            Field { coordinates: Point { x: Some(18), y: Some(0) }, value: Some(2) }
+        );
+        let road = a_star_resolver(fs.clone(), aps.clone(), 20, start_end).unwrap();
+
+        assert_eq!(road.cost, 10 * 19 + 18);
+        assert_eq!(a_star_uniform_cost_resolver(fs.clone(), aps.clone(), 20, start_end, 1_u16).unwrap(), (road.clone(), 208));
+        assert_eq!(a_star_uniform_cost_resolver(fs.clone(), aps.clone(), 20, start_end, 0.5_f32).unwrap().1, 104.0);
+        assert_eq!(a_star_uniform_cost_resolver(fs.clone(), aps.clone(), 20, start_end, 1_u8).unwrap().1, 208);
+        assert!(a_star_uniform_cost_resolver(fs.clone(), aps.clone(), 20, start_end, 2_u8).is_err());
+        assert!(a_star_uniform_cost_resolver(fs.clone(), aps.clone(), 20, start_end, 0_u32).is_err());
+
+        let steps: Vec<AStarStep<f32>> = AStarSearch::with_move_cost(fs, aps, 20, start_end, 0.1_f32).unwrap().take(3).collect();
+
+        assert_eq!(steps.iter().map(|step| step.g_cost).collect::<Vec<f32>>(), vec![0.0, 0.1, 0.1 + 0.1]);
+    }
+
+    #[test]
+    fn a_star_uniform_cost_resolver_unexpanded_overflow_test() {
+        let matrix = vec![
+            vec![1, 2, 0, 0],
+            vec![0, 0, 0, 0],
+            vec![0, 0, 0, 0],
+            vec![0, 0, 0, 0]
+        ];
+        let (fs, aps) = crate::graph::fs_aps_from_matrix(matrix).unwrap();
+        let start_end = (
+            Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(1) },
+            Field { coordinates: Point { x: Some(0), y: Some(1) }, value: Some(2) }
+        );
+
+        assert_eq!(a_star_uniform_cost_resolver(fs.clone(), aps.clone(), 4, start_end, 100_u8).unwrap().1, 100);
+        assert!(a_star_uniform_cost_resolver(fs, aps, 4, start_end, 255_u8).is_ok());
+    }
+
+    #[test]
+    fn is_cost_lower_or_equal_test() {
+        assert!(is_cost_lower_or_equal(Some(1), Some(2)));
+        assert!(! is_cost_lower_or_equal(Some(3), Some(2)));
+        assert!(is_cost_lower_or_equal(Some(3), None));
+        assert!(is_cost_lower_or_equal::<u8>(None, None));
+        assert!(! is_cost_lower_or_equal(None, Some(255_u8)));
+    }
+
+    #[test]
+    fn bidirectional_a_star_resolver_corridor_test() {
+        let matrix = vec![
+            vec![1, 0, 0, 0, 0, 0],
+            vec![-1, -1, -1, -1, -1, 0],
+            vec![0, 0, 0, 0, 0, 0],
+            vec![0, -1, -1, -1, -1, -1],
+            vec![0, 0, 0, 0, 0, 0],
+            vec![-1, -1, -1, -1, -1, 2]
+        ];
+        let (fs, aps) = crate::graph::fs_aps_from_matrix(matrix).unwrap();
+        let start_end = (
+            Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(1) },
+            Field { coordinates: Point { x: Some(5), y: Some(5) }, value: Some(2) }
+        );
+
+        let road = bidirectional_a_star_resolver(fs.clone(), aps.clone(), 6, start_end).unwrap();
+
+        assert_eq!(road.len(), 21);
+        assert_eq!(road.len(), a_star_resolver(fs, aps, 6, start_end).unwrap().len());
+        assert_eq!(road.first(), Some(&start_end.0.coordinates));
+        assert_eq!(road.last(), Some(&start_end.1.coordinates));
+    }
+
+    #[test]
+    fn a_star_search_steps_test() {
+        let matrix = vec![
+            vec![1, 0, -1],
+            vec![0, 0, -1],
+            vec![-1, -1, 2]
+        ];
+        let (fs, aps) = crate::graph::fs_aps_from_matrix(matrix).unwrap();
+        let start_end = (
+            Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(1) },
+            Field { coordinates: Point { x: Some(2), y: Some(2) }, value: Some(2) }
+        );
+
+        let mut search = AStarSearch::new(fs, aps, 3, start_end).unwrap();
+        let steps: Vec<AStarStep> = search.by_ref().collect();
+
+        assert_eq!(steps.len(), 4);
+        assert!(steps.iter().all(|step| step.road.is_none() && step.f_cost == step.g_cost + step.h_cost));
+
+        for (step_index, step) in steps.iter().enumerate() {
+            assert_eq!(step.closed_fields.len(), step_index + 1);
+        }
+
+        assert_eq!(search.outcome(), Some(Err("It seem that it has no end to this level")));
+        assert_eq!(search.next(), None);
+        assert_eq!(search.stats().nodes_expanded, 4);
+    }
 }
@@ -0,0 +1,398 @@
+//! # Description
+//! This is the ARA* (Anytime Repairing A*) algorithm module.
+//! A first road is found quickly with a heuristic multiplied by a weight bigger than 1.
+//! Then the weight is decreased and the previous search is repaired while the search budget remains.
+//! Each road comes with a bound: the road cost is never bigger than the bound multiplied by the shorter road cost.
+//! [For more explainations](https://papers.nips.cc/paper/2382-ara-anytime-a-with-provable-bounds-on-sub-optimality)
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::time::Instant;
+
+pub use crate::graph::{Field, Point};
+pub use crate::a_star::get_manhattan_distance_heuristic;
+pub use crate::path::Path;
+pub use crate::stats::SearchStats;
+use crate::graph::get_element_childs_slice;
+use crate::bfs::get_index_road_from_parents;
+use crate::stats::{record_road_search, RoadCost};
+
+/// The limit given to a ARA* search.
+/// `MaxExpansions` is the number of fields which can be expanded.
+/// `Deadline` is the moment when the search must stop.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SearchBudget {
+    MaxExpansions(usize),
+    Deadline(Instant)
+}
+
+/// A road found by the ARA* search.
+/// The road cost is never bigger than `suboptimality_bound` multiplied by the shorter road cost.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AraStarSolution {
+    pub road: Path,
+    pub suboptimality_bound: f64
+}
+
+impl RoadCost for AraStarSolution {
+    fn road_cost(&self) -> Option<usize> {
+        return Some(self.road.cost);
+    }
+}
+
+/// Field waiting inside the open list with his weighted cost.
+/// The order is reversed for get the smaller cost first from a `BinaryHeap`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct AraStarOpenField {
+    weighted_cost: f64,
+    index: usize
+}
+
+impl Eq for AraStarOpenField {}
+
+impl PartialOrd for AraStarOpenField {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for AraStarOpenField {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return other.weighted_cost.total_cmp(&self.weighted_cost).then_with(|| other.index.cmp(&self.index));
+    }
+}
+
+/// The state of a ARA* search kept between two weights.
+struct AraStarSearch<'a> {
+    fs: &'a [Field],
+    aps: &'a [u32],
+    matrix_size: usize,
+    heuristics: Vec<u32>,
+    weights: Vec<Option<u32>>,
+    parents: Vec<Option<usize>>,
+    opened: Vec<bool>,
+    closed: Vec<bool>,
+    inconsistent: Vec<bool>,
+    open_list: BinaryHeap<AraStarOpenField>,
+    heuristic_weight: f64,
+    expansions: usize,
+    budget: SearchBudget,
+    stats: &'a mut SearchStats
+}
+
+impl AraStarSearch<'_> {
+    fn weighted_cost(&self, index: usize) -> f64 {
+        return self.weights[index].unwrap_or(u32::MAX) as f64 + self.heuristic_weight * self.heuristics[index] as f64;
+    }
+
+    fn push_open(&mut self, index: usize) {
+        self.opened[index] = true;
+        self.open_list.push(AraStarOpenField { weighted_cost: self.weighted_cost(index), index });
+        self.stats.update_open_list_size(self.open_list.len());
+    }
+
+    fn is_budget_exhausted(&self) -> bool {
+        return match self.budget {
+            SearchBudget::MaxExpansions(max_expansions) => self.expansions >= max_expansions,
+            SearchBudget::Deadline(deadline) => Instant::now() >= deadline
+        };
+    }
+
+    /// Expand the fields until the end cannot be improved with the current weight.
+    /// Return false when the budget is exhausted before.
+    fn improve_path(&mut self, end_index: usize) -> Result<bool, &'static str> {
+        while let Some(open_field) = self.open_list.peek().cloned() {
+            let current_index = open_field.index;
+
+            if ! self.opened[current_index] || open_field.weighted_cost != self.weighted_cost(current_index) {
+                self.open_list.pop();
+                continue;
+            }
+
+            if self.weights[end_index].is_some_and(|end_weight| end_weight as f64 <= open_field.weighted_cost) {
+                break;
+            }
+
+            if self.is_budget_exhausted() {
+                return Ok(false);
+            }
+
+            self.open_list.pop();
+            self.opened[current_index] = false;
+            self.closed[current_index] = true;
+            self.expansions += 1;
+            self.stats.nodes_expanded += 1;
+
+            let child_weight = self.weights[current_index].unwrap() + 1;
+
+            for child in get_element_childs_slice(self.fs, self.aps, current_index)? {
+                if child.value == Some(-1) {
+                    continue;
+                }
+
+                let child_index = child.coordinates.get_index(self.matrix_size)?;
+
+                if child_index >= self.weights.len() || self.weights[child_index].is_some_and(|weight| weight <= child_weight) {
+                    continue;
+                }
+
+                let is_improved = self.weights[child_index].is_some();
+
+                self.weights[child_index] = Some(child_weight);
+                self.parents[child_index] = Some(current_index);
+
+                if self.closed[child_index] {
+                    self.inconsistent[child_index] = true;
+                } else {
+                    if is_improved {
+                        self.stats.reopened_nodes += 1;
+                    }
+
+                    self.push_open(child_index);
+                    self.stats.nodes_generated += 1;
+                }
+            }
+        }
+
+        return Ok(true);
+    }
+
+    /// Get the bound of the current road from the fields which are not expanded yet.
+    fn suboptimality_bound(&self, end_index: usize) -> f64 {
+        let end_weight = self.weights[end_index].unwrap_or(u32::MAX) as f64;
+        let smaller_cost = (0..self.weights.len())
+            .filter(|index| self.opened[*index] || self.inconsistent[*index])
+            .filter_map(|index| self.weights[index].map(|weight| weight + self.heuristics[index]))
+            .min();
+
+        return match smaller_cost {
+            Some(smaller_cost) if smaller_cost > 0 => self.heuristic_weight.min(end_weight / smaller_cost as f64).max(1.0),
+            _ => 1.0
+        };
+    }
+
+    /// The parents of a field can be improved after the end weight is computed,
+    /// so the road can be shorter than the end weight.
+    fn solution(&self, end_index: usize) -> Result<AraStarSolution, &'static str> {
+        let road = get_index_road_from_parents(&self.parents, self.matrix_size, end_index)?;
+
+        return Ok(AraStarSolution {
+            road: Path::new(road),
+            suboptimality_bound: self.suboptimality_bound(end_index)
+        });
+    }
+}
+
+/// ARA* resolver function.
+/// It's return all the roads found from the first one to the best one.
+/// The search start with the `initial_weight` on the heuristic and remove `weight_step` after each road
+/// until the road is proved to be the shorter one or the budget is exhausted.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::ara_star::{ ara_star_resolver, SearchBudget };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![2, 0, 0],
+///     vec![-1, -1, 0],
+///     vec![1, 0, 0]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(2), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(2)
+///     }
+/// );
+///
+/// let solutions = ara_star_resolver(fs_example, aps_example, matrix_example.len(), start_end_fields, 3.0, 0.5, SearchBudget::MaxExpansions(100)).unwrap();
+/// let best_solution = solutions.last().unwrap();
+///
+/// assert_eq!(best_solution.road.cost, 6);
+/// assert_eq!(best_solution.suboptimality_bound, 1.0);
+/// ```
+pub fn ara_star_resolver(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), initial_weight: f64, weight_step: f64, budget: SearchBudget) -> Result<Vec<AraStarSolution>, &'static str> {
+    return ara_star_resolver_with_stats(fs, aps, matrix_size, start_end_point, initial_weight, weight_step, budget, &mut SearchStats::new());
+}
+
+/// ARA* resolver function which fill the search statistics of all the searches.
+/// The inconsistent fields added again to the open list after a weight change are the reopened fields,
+/// and the road cost is the cost of the best road.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::ara_star::{ ara_star_resolver_with_stats, SearchBudget, SearchStats };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![2, 0, 0],
+///     vec![-1, -1, 0],
+///     vec![1, 0, 0]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(2), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(2)
+///     }
+/// );
+/// let mut stats = SearchStats::new();
+///
+/// ara_star_resolver_with_stats(fs_example, aps_example, matrix_example.len(), start_end_fields, 3.0, 0.5, SearchBudget::MaxExpansions(100), &mut stats).unwrap();
+///
+/// assert_eq!(stats.path_cost, Some(6));
+/// assert!(stats.nodes_expanded > 0);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn ara_star_resolver_with_stats(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), initial_weight: f64, weight_step: f64, budget: SearchBudget, stats: &mut SearchStats) -> Result<Vec<AraStarSolution>, &'static str> {
+    return record_road_search(stats, |stats| ara_star_search(fs, aps, matrix_size, start_end_point, initial_weight, weight_step, budget, stats));
+}
+
+#[allow(clippy::too_many_arguments)]
+fn ara_star_search(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), initial_weight: f64, weight_step: f64, budget: SearchBudget, stats: &mut SearchStats) -> Result<Vec<AraStarSolution>, &'static str> {
+    if fs.is_empty() || aps.is_empty() || start_end_point == (Field::new(), Field::new()) {
+        return Err("The parameters MUST be initializes");
+    }
+
+    if initial_weight.is_nan() || initial_weight < 1.0 || weight_step.is_nan() || weight_step <= 0.0 {
+        return Err("The heuristic weight cannot be lower than 1 and the weight step MUST be positive");
+    }
+
+    let (start_point, end_point) = start_end_point;
+    let fields_number = aps.len() - 1;
+    let start_index = start_point.coordinates.get_index(matrix_size)?;
+    let end_index = end_point.coordinates.get_index(matrix_size)?;
+
+    if start_index >= fields_number || end_index >= fields_number {
+        return Err("The target point cannot be found inside the matrix");
+    }
+
+    let mut heuristics: Vec<u32> = Vec::with_capacity(fields_number);
+
+    for index in 0..fields_number {
+        heuristics.push(get_manhattan_distance_heuristic(Point::from_index(index, matrix_size)?, end_point.coordinates) as u32);
+    }
+
+    let mut search = AraStarSearch {
+        fs: &fs,
+        aps: &aps,
+        matrix_size,
+        heuristics,
+        weights: vec![None; fields_number],
+        parents: vec![None; fields_number],
+        opened: vec![false; fields_number],
+        closed: vec![false; fields_number],
+        inconsistent: vec![false; fields_number],
+        open_list: BinaryHeap::new(),
+        heuristic_weight: initial_weight,
+        expansions: 0,
+        budget,
+        stats
+    };
+    let mut solutions: Vec<AraStarSolution> = Vec::new();
+
+    search.weights[start_index] = Some(0);
+    search.push_open(start_index);
+
+    loop {
+        let is_finished = search.improve_path(end_index)?;
+
+        if ! is_finished {
+            break;
+        }
+
+        if search.weights[end_index].is_none() {
+            return Err("It seem that it has no end to this level");
+        }
+
+        let solution = search.solution(end_index)?;
+        let is_optimal = solution.suboptimality_bound <= 1.0;
+
+        if solutions.last().is_none_or(|last_solution| *last_solution != solution) {
+            solutions.push(solution);
+        }
+
+        if is_optimal {
+            break;
+        }
+
+        search.heuristic_weight = (search.heuristic_weight - weight_step).max(1.0);
+
+        let reopened_indexes: Vec<usize> = (0..fields_number)
+            .filter(|index| search.opened[*index] || search.inconsistent[*index])
+            .collect();
+
+        search.stats.reopened_nodes += search.inconsistent.iter().filter(|is_inconsistent| **is_inconsistent).count();
+
+        search.open_list.clear();
+        search.closed = vec![false; fields_number];
+        search.inconsistent = vec![false; fields_number];
+
+        for index in reopened_indexes {
+            search.push_open(index);
+        }
+    }
+
+    if solutions.is_empty() {
+        return Err("The search budget is exhausted before finding a road");
+    }
+
+    return Ok(solutions);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::fs_aps_from_matrix;
+
+    fn testing_data_detour() -> (Vec<Field>, Vec<u32>, (Field, Field)) {
+        let matrix = vec![
+            vec![1, 0, 0, 0, 0, 0],
+            vec![0, -1, -1, -1, -1, 0],
+            vec![0, -1, 0, 0, -1, 0],
+            vec![0, 0, 0, 0, -1, 0],
+            vec![-1, -1, -1, 0, -1, 0],
+            vec![2, 0, 0, 0, 0, 0]
+        ];
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let start_end = (
+            Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(1) },
+            Field { coordinates: Point { x: Some(5), y: Some(0) }, value: Some(2) }
+        );
+
+        return (fs, aps, start_end);
+    }
+
+    #[test]
+    fn ara_star_resolver_improve_test() {
+        let (fs, aps, start_end) = testing_data_detour();
+
+        let solutions = ara_star_resolver(fs, aps, 6, start_end, 5.0, 1.0, SearchBudget::MaxExpansions(1000)).unwrap();
+        let best_solution = solutions.last().unwrap();
+
+        assert_eq!(best_solution.road.cost, 11);
+        assert_eq!(best_solution.road.len(), 12);
+        assert_eq!(best_solution.suboptimality_bound, 1.0);
+
+        for solution in solutions.iter() {
+            assert!(solution.road.cost as f64 <= solution.suboptimality_bound * 11.0);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "The search budget is exhausted before finding a road")]
+    fn ara_star_resolver_exhausted_budget_test() {
+        let (fs, aps, start_end) = testing_data_detour();
+
+        ara_star_resolver(fs, aps, 6, start_end, 2.0, 0.5, SearchBudget::MaxExpansions(2)).unwrap();
+    }
+}
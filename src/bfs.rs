@@ -0,0 +1,243 @@
+//! # Description
+//! This is the BFS resolver module.
+//! On a bord where all the moves have the same cost, a BFS find the shorter path without any heuristic.
+//! It's also use for cross-check the A* results.
+//! [For more explainations](https://en.wikipedia.org/wiki/Breadth-first_search)
+
+use std::collections::VecDeque;
+
+pub use crate::graph::{Field, Point};
+pub use crate::stats::SearchStats;
+pub use crate::path::Path;
+use crate::graph::get_element_childs_slice;
+use crate::stats::record_road_search;
+
+/// BFS resolver function.
+/// It's find the shorter path between two points of a graph.
+/// The input and the output are the same as the `a_star_resolver` function.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::bfs::bfs_resolver;
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![2, 0, 0],
+///     vec![-1, -1, 0],
+///     vec![1, 0, 0]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(2), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(2)
+///     }
+/// );
+///
+/// assert_eq!(bfs_resolver(fs_example, aps_example, matrix_example.len(), start_end_fields).unwrap().points, vec![
+///     Point { x: Some(2), y: Some(0) },
+///     Point { x: Some(2), y: Some(1) },
+///     Point { x: Some(2), y: Some(2) },
+///     Point { x: Some(1), y: Some(2) },
+///     Point { x: Some(0), y: Some(2) },
+///     Point { x: Some(0), y: Some(1) },
+///     Point { x: Some(0), y: Some(0) }
+/// ]);
+/// ```
+pub fn bfs_resolver(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field)) -> Result<Path, &'static str> {
+    return bfs_resolver_with_stats(fs, aps, matrix_size, start_end_point, &mut SearchStats::new());
+}
+
+/// The same search as the `bfs_resolver` function which also fill the search statistics.
+/// A BFS never reopen a field, so `reopened_nodes` is always 0.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::bfs::{ bfs_resolver_with_stats, SearchStats };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![0, 1],
+///     vec![-1, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(0), y: Some(1) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(1), y: Some(1) },
+///         value: Some(2)
+///     }
+/// );
+/// let mut stats = SearchStats::new();
+///
+/// bfs_resolver_with_stats(fs_example, aps_example, matrix_example.len(), start_end_fields, &mut stats).unwrap();
+///
+/// assert_eq!(stats.path_cost, Some(1));
+/// assert_eq!(stats.nodes_generated, 2);
+/// assert_eq!(stats.reopened_nodes, 0);
+/// ```
+pub fn bfs_resolver_with_stats(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), stats: &mut SearchStats) -> Result<Path, &'static str> {
+    return record_road_search(stats, |stats| bfs_road_search(fs, aps, matrix_size, start_end_point, stats));
+}
+
+fn bfs_road_search(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), stats: &mut SearchStats) -> Result<Path, &'static str> {
+    if fs.is_empty() || aps.is_empty() || start_end_point == (Field::new(), Field::new()) {
+        return Err("The parameters MUST be initializes");
+    }
+
+    let (start_point, end_point) = start_end_point;
+    let start_index = start_point.coordinates.get_index(matrix_size)?;
+    let end_index = end_point.coordinates.get_index(matrix_size)?;
+
+    let (distances, parents) = bfs_search_with_stats(&fs, &aps, matrix_size, start_index, Some(end_index), &|_, _| true, stats)?;
+
+    if distances.get(end_index).cloned().flatten().is_none() {
+        return Err("It seem that it has no end to this level");
+    }
+
+    return Ok(Path::new(get_index_road_from_parents(&parents, matrix_size, end_index)?));
+}
+
+/// Get the distance from a start point to all the fields of the bord.
+/// The distances are stored by the index of the field inside the flatten matrix.
+/// The unreachable fields and the walls have no distance.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::bfs::bfs_distance_map;
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![0, 1],
+///     vec![-1, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_field = Field {
+///     coordinates: Point { x: Some(0), y: Some(1) },
+///     value: Some(1)
+/// };
+///
+/// assert_eq!(bfs_distance_map(fs_example, aps_example, matrix_example.len(), start_field).unwrap(), vec![Some(1), Some(0), None, Some(1)]);
+/// ```
+pub fn bfs_distance_map(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_point: Field) -> Result<Vec<Option<usize>>, &'static str> {
+    if fs.is_empty() || aps.is_empty() || start_point == Field::new() {
+        return Err("The parameters MUST be initializes");
+    }
+
+    let start_index = start_point.coordinates.get_index(matrix_size)?;
+    let (distances, _) = bfs_search(&fs, &aps, matrix_size, start_index, None, &|_, _| true)?;
+
+    return Ok(distances);
+}
+
+/// The distances and the parents of each index found by a BFS.
+pub(crate) type BfsTree = (Vec<Option<usize>>, Vec<Option<usize>>);
+
+/// Run a BFS from the start index and return the distances and the parents of each index.
+/// The search stop as soon as the end index is reached when there is one.
+/// The `is_allowed_move` closure can forbid a move between two indexes.
+pub(crate) fn bfs_search(fs: &[Field], aps: &[u32], matrix_size: usize, start_index: usize, end_index: Option<usize>, is_allowed_move: &dyn Fn(usize, usize) -> bool) -> Result<BfsTree, &'static str> {
+    return bfs_search_with_stats(fs, aps, matrix_size, start_index, end_index, is_allowed_move, &mut SearchStats::new());
+}
+
+/// The same search as the `bfs_search` function which also count the expanded and generated fields.
+/// The counts are added to the statistics, so many searches can fill the same statistics.
+pub(crate) fn bfs_search_with_stats(fs: &[Field], aps: &[u32], matrix_size: usize, start_index: usize, end_index: Option<usize>, is_allowed_move: &dyn Fn(usize, usize) -> bool, stats: &mut SearchStats) -> Result<BfsTree, &'static str> {
+    let fields_number = aps.len().saturating_sub(1);
+
+    if start_index >= fields_number {
+        return Err("The start point cannot be found inside the graph");
+    }
+
+    let mut distances: Vec<Option<usize>> = vec![None; fields_number];
+    let mut parents: Vec<Option<usize>> = vec![None; fields_number];
+    let mut queue: VecDeque<usize> = VecDeque::new();
+
+    distances[start_index] = Some(0);
+    queue.push_back(start_index);
+    stats.update_open_list_size(queue.len());
+
+    while let Some(current_index) = queue.pop_front() {
+        stats.nodes_expanded += 1;
+
+        if Some(current_index) == end_index {
+            break;
+        }
+
+        let current_distance = distances[current_index].unwrap_or(0);
+
+        for child in get_element_childs_slice(fs, aps, current_index)? {
+            if child.value == Some(-1) {
+                continue;
+            }
+
+            let child_index = child.coordinates.get_index(matrix_size)?;
+
+            if child_index >= fields_number || distances[child_index].is_some() || ! is_allowed_move(current_index, child_index) {
+                continue;
+            }
+
+            distances[child_index] = Some(current_distance + 1);
+            parents[child_index] = Some(current_index);
+            queue.push_back(child_index);
+            stats.nodes_generated += 1;
+        }
+
+        stats.update_open_list_size(queue.len());
+    }
+
+    return Ok((distances, parents));
+}
+
+/// Rebuild the road from the start to the end index with the parents of each index.
+pub(crate) fn get_index_road_from_parents(parents: &[Option<usize>], matrix_size: usize, end_index: usize) -> Result<Vec<Point>, &'static str> {
+    let mut start_to_end_road: Vec<Point> = vec![Point::from_index(end_index, matrix_size)?];
+    let mut current_index = end_index;
+
+    while let Some(parent_index) = parents.get(current_index).cloned().flatten() {
+        start_to_end_road.push(Point::from_index(parent_index, matrix_size)?);
+        current_index = parent_index;
+    }
+
+    start_to_end_road.reverse();
+
+    return Ok(start_to_end_road);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::fs_aps_from_matrix;
+
+    #[test]
+    fn bfs_search_forbidden_move_test() {
+        let matrix = vec![
+            vec![1, 0, 0],
+            vec![0, -1, 0],
+            vec![0, 0, 2]
+        ];
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+
+        let (distances, _) = bfs_search(&fs, &aps, 3, 0, None, &|from, to| ! (from == 0 && to == 1)).unwrap();
+
+        assert_eq!(distances[1], Some(7));
+        assert_eq!(distances[8], Some(4));
+        assert_eq!(distances[4], None);
+    }
+
+    #[test]
+    fn get_index_road_from_parents_test() {
+        let parents = vec![None, Some(0), Some(1), Some(2)];
+        let expected_output = vec![Point { x: Some(0), y: Some(0) }, Point { x: Some(0), y: Some(1) }, Point { x: Some(1), y: Some(0) }, Point { x: Some(1), y: Some(1) }];
+
+        assert_eq!(get_index_road_from_parents(&parents, 2, 3).unwrap(), expected_output);
+    }
+}
@@ -0,0 +1,316 @@
+//! # Description
+//! This is the Conflict-Based Search (CBS) module.
+//! It's find roads without collision for many agents on the same bord with the smaller sum of costs.
+//! Each agent is resolved alone with a space-time A*, then each conflict between two agents
+//! is solved by adding a constraint on one agent or on the other in two branches of a constraint tree.
+//! [For more explainations](https://www.sciencedirect.com/science/article/pii/S0004370214001386)
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+pub use crate::graph::{Field, Point};
+pub use crate::stats::SearchStats;
+use crate::space_time::{space_time_a_star, SpaceTimeQuery};
+use crate::stats::record_road_search;
+
+/// The maximum number of constraint tree nodes expanded before giving up.
+const MAX_CONFLICT_TREE_EXPANSIONS: usize = 10_000;
+
+/// A constraint on one agent: it cannot be on a field or do a move at a timestep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Constraint {
+    Vertex { agent: usize, index: usize, time: usize },
+    Edge { agent: usize, from_index: usize, to_index: usize, time: usize }
+}
+
+/// A collision between two agents at a timestep.
+/// For a vertex conflict, both agents are on the same field.
+/// For a edge conflict, the agents swap their fields: the first agent go from `from_index` to `to_index`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Conflict {
+    Vertex { agents: (usize, usize), index: usize, time: usize },
+    Edge { agents: (usize, usize), from_index: usize, to_index: usize, time: usize }
+}
+
+/// A node of the constraint tree with the roads of all the agents which respect its constraints.
+#[derive(Debug, Clone)]
+struct ConflictTreeNode {
+    constraints: Vec<Constraint>,
+    roads: Vec<Vec<usize>>,
+    cost: usize
+}
+
+/// Get the field of an agent at a timestep, the agent stay on his end after his road.
+fn get_position(road: &[usize], time: usize) -> usize {
+    return road[time.min(road.len() - 1)];
+}
+
+fn get_sum_of_costs(roads: &[Vec<usize>]) -> usize {
+    return roads.iter().map(|road| road.len() - 1).sum();
+}
+
+/// Get the first conflict in time between the roads of the agents.
+fn find_first_conflict(roads: &[Vec<usize>]) -> Option<Conflict> {
+    let max_time = roads.iter().map(|road| road.len()).max().unwrap_or(0);
+
+    for time in 0..max_time {
+        for first_agent in 0..roads.len() {
+            for second_agent in first_agent + 1..roads.len() {
+                let first_index = get_position(&roads[first_agent], time);
+                let second_index = get_position(&roads[second_agent], time);
+
+                if first_index == second_index {
+                    return Some(Conflict::Vertex { agents: (first_agent, second_agent), index: first_index, time });
+                }
+
+                if time > 0 {
+                    let first_previous_index = get_position(&roads[first_agent], time - 1);
+                    let second_previous_index = get_position(&roads[second_agent], time - 1);
+
+                    if first_previous_index == second_index && second_previous_index == first_index {
+                        return Some(Conflict::Edge { agents: (first_agent, second_agent), from_index: first_previous_index, to_index: first_index, time });
+                    }
+                }
+            }
+        }
+    }
+
+    return None;
+}
+
+/// Resolve one agent with the space-time A* and the constraints of this agent.
+fn resolve_agent(fs: &[Field], aps: &[u32], matrix_size: usize, agent: usize, start_end_index: (usize, usize), constraints: &[Constraint], stats: &mut SearchStats) -> Result<Option<Vec<usize>>, &'static str> {
+    let (start_index, end_index) = start_end_index;
+    let agent_constraints: Vec<Constraint> = constraints.iter()
+        .filter(|constraint| match constraint {
+            Constraint::Vertex { agent: constraint_agent, .. } | Constraint::Edge { agent: constraint_agent, .. } => *constraint_agent == agent
+        })
+        .cloned()
+        .collect();
+
+    let last_constraint_time = agent_constraints.iter()
+        .map(|constraint| match constraint {
+            Constraint::Vertex { time, .. } | Constraint::Edge { time, .. } => *time
+        })
+        .max()
+        .unwrap_or(0);
+    let goal_min_time = agent_constraints.iter()
+        .filter_map(|constraint| match constraint {
+            Constraint::Vertex { index, time, .. } if *index == end_index => Some(*time + 1),
+            _ => None
+        })
+        .max()
+        .unwrap_or(0);
+
+    let query = SpaceTimeQuery {
+        start_index,
+        start_time: 0,
+        end_index,
+        goal_min_time,
+        max_time: last_constraint_time + aps.len(),
+        window_end: None,
+        periodic_times: None
+    };
+    let is_allowed_move = |from_index: usize, to_index: usize, time: usize| -> bool {
+        return ! agent_constraints.iter().any(|constraint| match constraint {
+            Constraint::Vertex { index, time: constraint_time, .. } => *index == to_index && *constraint_time == time,
+            Constraint::Edge { from_index: constraint_from, to_index: constraint_to, time: constraint_time, .. } => {
+                *constraint_from == from_index && *constraint_to == to_index && *constraint_time == time
+            }
+        });
+    };
+
+    return space_time_a_star(fs, aps, matrix_size, &query, &is_allowed_move, stats);
+}
+
+/// Conflict-Based Search resolver function.
+/// It's take a start and a end for each agent and return the roads of the agents with the smaller sum of costs,
+/// where two agents are never on the same field at the same timestep and never swap their fields.
+/// The point of a road at the index `t` is the field of the agent at the timestep `t`,
+/// so a agent which waits has the same point many times. A agent stays on his end after his road.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::cbs::cbs_resolver;
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![1, 0, 1],
+///     vec![-1, 0, -1],
+///     vec![-1, -1, -1]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let first_agent = (Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(0), y: Some(2) },
+///         value: Some(2)
+///     }
+/// );
+/// let second_agent = (first_agent.1, first_agent.0);
+///
+/// let roads = cbs_resolver(fs_example, aps_example, matrix_example.len(), vec![first_agent, second_agent]).unwrap();
+///
+/// assert_eq!(roads[0].len() - 1 + roads[1].len() - 1, 7);
+/// ```
+pub fn cbs_resolver(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_points: Vec<(Field, Field)>) -> Result<Vec<Vec<Point>>, &'static str> {
+    return cbs_resolver_with_stats(fs, aps, matrix_size, start_end_points, &mut SearchStats::new());
+}
+
+/// Conflict-Based Search resolver function which fill the search statistics.
+/// The statistics count the states of all the space-time A* searches of the agents, for all the nodes of the constraint tree.
+/// The path cost is the sum of the costs of the agents roads.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::cbs::{ cbs_resolver_with_stats, SearchStats };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![1, 0, 1],
+///     vec![-1, 0, -1],
+///     vec![-1, -1, -1]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let first_agent = (Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(0), y: Some(2) },
+///         value: Some(2)
+///     }
+/// );
+/// let second_agent = (first_agent.1, first_agent.0);
+/// let mut stats = SearchStats::new();
+///
+/// cbs_resolver_with_stats(fs_example, aps_example, matrix_example.len(), vec![first_agent, second_agent], &mut stats).unwrap();
+///
+/// assert_eq!(stats.path_cost, Some(7));
+/// assert!(stats.nodes_expanded > 0);
+/// ```
+pub fn cbs_resolver_with_stats(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_points: Vec<(Field, Field)>, stats: &mut SearchStats) -> Result<Vec<Vec<Point>>, &'static str> {
+    return record_road_search(stats, |stats| cbs_search(fs, aps, matrix_size, start_end_points, stats));
+}
+
+fn cbs_search(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_points: Vec<(Field, Field)>, stats: &mut SearchStats) -> Result<Vec<Vec<Point>>, &'static str> {
+    if fs.is_empty() || aps.is_empty() || start_end_points.is_empty() {
+        return Err("The parameters MUST be initializes");
+    }
+
+    let fields_number = aps.len() - 1;
+    let mut start_end_indexes: Vec<(usize, usize)> = Vec::new();
+
+    for (start_point, end_point) in start_end_points.iter() {
+        let start_end_index = (start_point.coordinates.get_index(matrix_size)?, end_point.coordinates.get_index(matrix_size)?);
+
+        if start_end_index.0 >= fields_number || start_end_index.1 >= fields_number {
+            return Err("The target point cannot be found inside the matrix");
+        }
+
+        if start_end_indexes.iter().any(|(start_index, end_index)| *start_index == start_end_index.0 || *end_index == start_end_index.1) {
+            return Err("Two agents cannot have the same start or the same end");
+        }
+
+        start_end_indexes.push(start_end_index);
+    }
+
+    let mut roads: Vec<Vec<usize>> = Vec::new();
+
+    for (agent, start_end_index) in start_end_indexes.iter().enumerate() {
+        roads.push(resolve_agent(&fs, &aps, matrix_size, agent, *start_end_index, &[], stats)?.ok_or("It seem that it has no end to this level")?);
+    }
+
+    let mut conflict_tree: Vec<ConflictTreeNode> = vec![ConflictTreeNode { constraints: Vec::new(), cost: get_sum_of_costs(&roads), roads }];
+    let mut open_list: BinaryHeap<Reverse<(usize, usize)>> = BinaryHeap::new();
+    let mut expansions = 0;
+
+    open_list.push(Reverse((conflict_tree[0].cost, 0)));
+
+    while let Some(Reverse((_, node_index))) = open_list.pop() {
+        if expansions >= MAX_CONFLICT_TREE_EXPANSIONS {
+            return Err("The search budget is exhausted before finding a road");
+        }
+
+        expansions += 1;
+
+        let node = conflict_tree[node_index].clone();
+        let conflict = match find_first_conflict(&node.roads) {
+            Some(conflict) => conflict,
+            None => {
+                let mut agents_roads: Vec<Vec<Point>> = Vec::new();
+
+                for road in node.roads {
+                    agents_roads.push(road.into_iter().map(|index| Point::from_index(index, matrix_size)).collect::<Result<Vec<Point>, &'static str>>()?);
+                }
+
+                return Ok(agents_roads);
+            }
+        };
+
+        let new_constraints = match conflict {
+            Conflict::Vertex { agents: (first_agent, second_agent), index, time } => [
+                Constraint::Vertex { agent: first_agent, index, time },
+                Constraint::Vertex { agent: second_agent, index, time }
+            ],
+            Conflict::Edge { agents: (first_agent, second_agent), from_index, to_index, time } => [
+                Constraint::Edge { agent: first_agent, from_index, to_index, time },
+                Constraint::Edge { agent: second_agent, from_index: to_index, to_index: from_index, time }
+            ]
+        };
+
+        for new_constraint in new_constraints.iter() {
+            let agent = match new_constraint {
+                Constraint::Vertex { agent, .. } | Constraint::Edge { agent, .. } => *agent
+            };
+            let mut constraints = node.constraints.clone();
+            constraints.push(*new_constraint);
+
+            if let Some(road) = resolve_agent(&fs, &aps, matrix_size, agent, start_end_indexes[agent], &constraints, stats)? {
+                let mut roads = node.roads.clone();
+                roads[agent] = road;
+
+                let cost = get_sum_of_costs(&roads);
+
+                conflict_tree.push(ConflictTreeNode { constraints, roads, cost });
+                open_list.push(Reverse((cost, conflict_tree.len() - 1)));
+            }
+        }
+    }
+
+    return Err("It seem that it has no end to this level");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_first_conflict_test() {
+        assert_eq!(find_first_conflict(&[vec![0, 1, 2], vec![4, 3]]), None);
+        assert_eq!(find_first_conflict(&[vec![0, 1, 2], vec![3, 1]]), Some(Conflict::Vertex { agents: (0, 1), index: 1, time: 1 }));
+        assert_eq!(find_first_conflict(&[vec![0, 1, 2], vec![1, 0]]), Some(Conflict::Edge { agents: (0, 1), from_index: 0, to_index: 1, time: 1 }));
+        assert_eq!(find_first_conflict(&[vec![0, 1, 2, 3], vec![5, 2]]), Some(Conflict::Vertex { agents: (0, 1), index: 2, time: 2 }));
+    }
+
+    #[test]
+    fn resolve_agent_goal_constraint_test() {
+        let matrix = vec![
+            vec![1, 0, 2],
+            vec![0, 0, 0],
+            vec![0, 0, 0]
+        ];
+        let (fs, aps) = crate::graph::fs_aps_from_matrix(matrix).unwrap();
+        let constraints = [Constraint::Vertex { agent: 0, index: 2, time: 4 }, Constraint::Vertex { agent: 1, index: 2, time: 6 }];
+
+        let road = resolve_agent(&fs, &aps, 3, 0, (0, 2), &constraints, &mut SearchStats::new()).unwrap().unwrap();
+
+        assert_eq!(road.len(), 6);
+        assert_ne!(road[4], 2);
+        assert_eq!(get_position(&road, 5), 2);
+    }
+}
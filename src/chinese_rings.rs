@@ -23,14 +23,11 @@
 ///     vec![true, false, true, true],
 ///     vec![true, true, true, true]
 /// ];
-
+///
 /// assert_eq!(chinese_rings_resolver(size), expected_output);
 /// ```
 pub fn chinese_rings_resolver(rings_number: usize) -> Vec<Vec<bool>> {
-    let mut chinese_rings: Vec<bool> = Vec::with_capacity(rings_number);
-    for _ in 0..rings_number + 1 {
-        chinese_rings.push(false);
-    }
+    let mut chinese_rings: Vec<bool> = vec![false; rings_number + 1];
 
     let mut mouvement_list: Vec<Vec<bool>> = vec![chinese_rings[1..].to_vec()];
 
@@ -40,12 +37,12 @@ pub fn chinese_rings_resolver(rings_number: usize) -> Vec<Vec<bool>> {
 
 }
 
-fn give_ring(rings: &mut Vec<bool>, index: usize, mouvement_list: &mut Vec<Vec<bool>>) {
+fn give_ring(rings: &mut [bool], index: usize, mouvement_list: &mut Vec<Vec<bool>>) {
     rings[index] = true;
     mouvement_list.push(rings[1..].to_vec());
 }
 
-fn take_ring(rings: &mut Vec<bool>, index: usize, mouvement_list: &mut Vec<Vec<bool>>) {
+fn take_ring(rings: &mut [bool], index: usize, mouvement_list: &mut Vec<Vec<bool>>) {
     rings[index] = false;
     mouvement_list.push(rings[1..].to_vec());
 }
@@ -0,0 +1,318 @@
+//! # Description
+//! This is the cooperative A* module (HCA* and windowed HCA*).
+//! The agents are planned one after the other with a space-time A*,
+//! each road is saved in a reservation table and the next agents cannot use the reserved fields and moves.
+//! It's faster than the Conflict-Based Search but the roads are not always the shorter ones
+//! and a road can be missed when an agent blocks the way of the next ones.
+//! [For more explainations](https://www.davidsilver.uk/wp-content/uploads/2020/03/coop-path-AIWisdom.pdf)
+
+use std::collections::{HashMap, HashSet};
+
+pub use crate::graph::{Field, Point};
+pub use crate::stats::SearchStats;
+use crate::space_time::{space_time_a_star, SpaceTimeQuery};
+use crate::stats::record_road_search;
+
+/// The fields and the moves reserved by the agents already planned.
+/// A field is reserved at a timestep, a move is reserved at the timestep of the arrival,
+/// and an agent parked on his end reserves the field from a timestep until the end of the search.
+/// The last reserved timestep of each field is kept on each reservation, so it's never searched inside the reserved fields.
+#[derive(Debug, Clone, Default)]
+struct ReservationTable {
+    fields: HashSet<(usize, usize)>,
+    moves: HashSet<(usize, usize, usize)>,
+    parked_fields: HashMap<usize, usize>,
+    last_reserved_times: HashMap<usize, usize>,
+    max_reserved_time: usize
+}
+
+impl ReservationTable {
+    fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Reserve the fields and the moves of a road which starts at `start_time`.
+    fn reserve_road(&mut self, road: &[usize], start_time: usize) {
+        for (step, index) in road.iter().enumerate() {
+            let time = start_time + step;
+            let last_reserved_time = self.last_reserved_times.entry(*index).or_insert(time);
+
+            *last_reserved_time = (*last_reserved_time).max(time);
+            self.max_reserved_time = self.max_reserved_time.max(time);
+            self.fields.insert((*index, time));
+
+            if step > 0 {
+                self.moves.insert((road[step - 1], *index, start_time + step));
+            }
+        }
+    }
+
+    fn park(&mut self, index: usize, time: usize) {
+        self.parked_fields.insert(index, time);
+    }
+
+    /// Check that an agent can go from `from_index` to `to_index` and arrive at `time`,
+    /// without being on a reserved field and without swapping his field with another agent.
+    fn is_allowed_move(&self, from_index: usize, to_index: usize, time: usize) -> bool {
+        let is_swap = from_index != to_index && self.moves.contains(&(to_index, from_index, time));
+
+        return ! self.fields.contains(&(to_index, time))
+            && ! is_swap
+            && self.parked_fields.get(&to_index).is_none_or(|parked_time| time < *parked_time);
+    }
+
+    /// Get the last timestep where the field is reserved, `None` when it is never reserved.
+    fn last_reserved_time(&self, index: usize) -> Option<usize> {
+        return self.last_reserved_times.get(&index).cloned();
+    }
+
+    fn last_time(&self) -> usize {
+        return self.max_reserved_time;
+    }
+}
+
+/// Plan the agents one after the other on all the timesteps, each agent stays on his end after his road.
+fn cooperative_a_star_full_roads(fs: &[Field], aps: &[u32], matrix_size: usize, start_end_indexes: &[(usize, usize)], stats: &mut SearchStats) -> Result<Vec<Vec<usize>>, &'static str> {
+    let fields_number = aps.len() - 1;
+    let mut reservation_table = ReservationTable::new();
+    let mut roads: Vec<Vec<usize>> = Vec::new();
+
+    for (start_index, end_index) in start_end_indexes.iter() {
+        let query = SpaceTimeQuery {
+            start_index: *start_index,
+            start_time: 0,
+            end_index: *end_index,
+            goal_min_time: reservation_table.last_reserved_time(*end_index).map_or(0, |time| time + 1),
+            max_time: reservation_table.last_time() + fields_number + 1,
+            window_end: None,
+            periodic_times: None
+        };
+        let is_allowed_move = |from_index: usize, to_index: usize, time: usize| reservation_table.is_allowed_move(from_index, to_index, time);
+
+        let road = space_time_a_star(fs, aps, matrix_size, &query, &is_allowed_move, stats)?.ok_or("It seem that it has no end to this level")?;
+
+        reservation_table.reserve_road(&road, 0);
+        reservation_table.park(*end_index, road.len() - 1);
+        roads.push(road);
+    }
+
+    return Ok(roads);
+}
+
+/// Plan the agents one after the other on `window` timesteps, then move all the agents and plan again,
+/// until all the agents are on their end at the beginning of a window.
+/// The first agent planned changes at each window.
+fn cooperative_a_star_windowed_roads(fs: &[Field], aps: &[u32], matrix_size: usize, start_end_indexes: &[(usize, usize)], window: usize, stats: &mut SearchStats) -> Result<Vec<Vec<usize>>, &'static str> {
+    let max_time = (aps.len() - 1) * start_end_indexes.len();
+    // A window longer than the search budget plans the same roads, and it cannot overflow the timesteps.
+    let window = window.min(max_time);
+    let max_time = max_time + window;
+    let mut roads: Vec<Vec<usize>> = start_end_indexes.iter().map(|(start_index, _)| vec![*start_index]).collect();
+    let agents_number = start_end_indexes.len();
+    let mut time = 0;
+
+    while roads.iter().zip(start_end_indexes.iter()).any(|(road, (_, end_index))| road.last() != Some(end_index)) {
+        if time >= max_time {
+            return Err("The search budget is exhausted before finding a road");
+        }
+
+        let mut reservation_table = ReservationTable::new();
+
+        let first_agent = (time / window) % agents_number;
+
+        for agent in (0..agents_number).map(|rank| (first_agent + rank) % agents_number) {
+            let (_, end_index) = &start_end_indexes[agent];
+            let road = &mut roads[agent];
+            let query = SpaceTimeQuery {
+                start_index: *road.last().unwrap(),
+                start_time: time,
+                end_index: *end_index,
+                goal_min_time: time + window,
+                max_time: time + window,
+                window_end: Some(time + window),
+                periodic_times: None
+            };
+            let is_allowed_move = |from_index: usize, to_index: usize, time: usize| reservation_table.is_allowed_move(from_index, to_index, time);
+
+            let window_road = space_time_a_star(fs, aps, matrix_size, &query, &is_allowed_move, stats)?.ok_or("It seem that it has no end to this level")?;
+
+            reservation_table.reserve_road(&window_road, time);
+            road.extend(window_road.into_iter().skip(1));
+        }
+
+        time += window;
+    }
+
+    for (road, (_, end_index)) in roads.iter_mut().zip(start_end_indexes.iter()) {
+        while road.len() > 1 && road[road.len() - 2] == *end_index {
+            road.pop();
+        }
+    }
+
+    return Ok(roads);
+}
+
+/// Cooperative A* resolver function.
+/// It's take a start and a end for each agent and return a road for each agent,
+/// where two agents are never on the same field at the same timestep and never swap their fields.
+/// The agents are planned in the order of `start_end_points`, the first agents have the priority.
+/// The point of a road at the index `t` is the field of the agent at the timestep `t`,
+/// so a agent which waits has the same point many times. A agent stays on his end after his road.
+///
+/// With `window` set, it's the windowed HCA*: each agent only plans the next `window` timesteps
+/// then all the agents plan again from their new fields, and the agent planned first changes at each window.
+/// With a too small window, the agents can turn around without end and the search budget is exhausted.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::cooperative::cooperative_a_star_resolver;
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![-1, 1, -1],
+///     vec![1, 0, 2],
+///     vec![-1, 2, -1]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let first_agent = (Field {
+///         coordinates: Point { x: Some(1), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(1), y: Some(2) },
+///         value: Some(2)
+///     }
+/// );
+/// let second_agent = (Field {
+///         coordinates: Point { x: Some(0), y: Some(1) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(2), y: Some(1) },
+///         value: Some(2)
+///     }
+/// );
+///
+/// let roads = cooperative_a_star_resolver(fs_example.clone(), aps_example.clone(), matrix_example.len(), vec![first_agent, second_agent], None).unwrap();
+///
+/// assert_eq!(roads[0].len() - 1, 2);
+/// assert_eq!(roads[1].len() - 1, 3);
+/// assert_eq!(roads[1][1], Point { x: Some(0), y: Some(1) });
+///
+/// let windowed_roads = cooperative_a_star_resolver(fs_example, aps_example, matrix_example.len(), vec![first_agent, second_agent], Some(2)).unwrap();
+///
+/// assert_eq!(windowed_roads[0].last(), Some(&first_agent.1.coordinates));
+/// assert_eq!(windowed_roads[1].last(), Some(&second_agent.1.coordinates));
+/// ```
+pub fn cooperative_a_star_resolver(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_points: Vec<(Field, Field)>, window: Option<usize>) -> Result<Vec<Vec<Point>>, &'static str> {
+    return cooperative_a_star_resolver_with_stats(fs, aps, matrix_size, start_end_points, window, &mut SearchStats::new());
+}
+
+/// Cooperative A* resolver function which fill the search statistics.
+/// The statistics count the states of all the space-time A* searches of the agents, for all the windows.
+/// The path cost is the sum of the costs of the agents roads.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::cooperative::{ cooperative_a_star_resolver_with_stats, SearchStats };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![-1, 1, -1],
+///     vec![1, 0, 2],
+///     vec![-1, 2, -1]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let first_agent = (Field {
+///         coordinates: Point { x: Some(1), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(1), y: Some(2) },
+///         value: Some(2)
+///     }
+/// );
+/// let second_agent = (Field {
+///         coordinates: Point { x: Some(0), y: Some(1) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(2), y: Some(1) },
+///         value: Some(2)
+///     }
+/// );
+/// let mut stats = SearchStats::new();
+///
+/// cooperative_a_star_resolver_with_stats(fs_example, aps_example, matrix_example.len(), vec![first_agent, second_agent], None, &mut stats).unwrap();
+///
+/// assert_eq!(stats.path_cost, Some(5));
+/// assert!(stats.nodes_expanded > 0);
+/// ```
+pub fn cooperative_a_star_resolver_with_stats(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_points: Vec<(Field, Field)>, window: Option<usize>, stats: &mut SearchStats) -> Result<Vec<Vec<Point>>, &'static str> {
+    return record_road_search(stats, |stats| cooperative_a_star_search(fs, aps, matrix_size, start_end_points, window, stats));
+}
+
+fn cooperative_a_star_search(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_points: Vec<(Field, Field)>, window: Option<usize>, stats: &mut SearchStats) -> Result<Vec<Vec<Point>>, &'static str> {
+    if fs.is_empty() || aps.is_empty() || start_end_points.is_empty() || window == Some(0) {
+        return Err("The parameters MUST be initializes");
+    }
+
+    let fields_number = aps.len() - 1;
+    let mut start_end_indexes: Vec<(usize, usize)> = Vec::new();
+
+    for (start_point, end_point) in start_end_points.iter() {
+        let start_end_index = (start_point.coordinates.get_index(matrix_size)?, end_point.coordinates.get_index(matrix_size)?);
+
+        if start_end_index.0 >= fields_number || start_end_index.1 >= fields_number {
+            return Err("The target point cannot be found inside the matrix");
+        }
+
+        if start_end_indexes.iter().any(|(start_index, end_index)| *start_index == start_end_index.0 || *end_index == start_end_index.1) {
+            return Err("Two agents cannot have the same start or the same end");
+        }
+
+        start_end_indexes.push(start_end_index);
+    }
+
+    let roads = match window {
+        Some(window) => cooperative_a_star_windowed_roads(&fs, &aps, matrix_size, &start_end_indexes, window, stats)?,
+        None => cooperative_a_star_full_roads(&fs, &aps, matrix_size, &start_end_indexes, stats)?
+    };
+    let mut agents_roads: Vec<Vec<Point>> = Vec::new();
+
+    for road in roads {
+        agents_roads.push(road.into_iter().map(|index| Point::from_index(index, matrix_size)).collect::<Result<Vec<Point>, &'static str>>()?);
+    }
+
+    return Ok(agents_roads);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reservation_table_test() {
+        let mut reservation_table = ReservationTable::new();
+
+        reservation_table.reserve_road(&[0, 1, 1, 2], 3);
+        reservation_table.park(2, 6);
+
+        assert!(! reservation_table.is_allowed_move(4, 1, 4));
+        assert!(reservation_table.is_allowed_move(4, 1, 6));
+        assert!(! reservation_table.is_allowed_move(1, 0, 4));
+        assert!(reservation_table.is_allowed_move(0, 0, 5));
+        assert!(! reservation_table.is_allowed_move(5, 2, 100));
+        assert_eq!(reservation_table.last_reserved_time(1), Some(5));
+        assert_eq!(reservation_table.last_reserved_time(7), None);
+        assert_eq!(reservation_table.last_time(), 6);
+
+        reservation_table.reserve_road(&[3, 1], 0);
+
+        assert_eq!(reservation_table.last_reserved_time(1), Some(5));
+        assert_eq!(reservation_table.last_reserved_time(3), Some(0));
+        assert_eq!(reservation_table.last_time(), 6);
+    }
+}
@@ -0,0 +1,88 @@
+//! # Description
+//! This is the cost module, for the roads costs of the A* resolvers.
+//! A cost can be a integer of any width or a float, and each operation on it is checked
+//! so a road too long for the cost type gives a error and never a wrong cost.
+
+use std::convert::TryFrom;
+use std::fmt::Debug;
+
+/// A type usable for the costs of the A* fields.
+/// The operations return `None` when the result cannot be represented by the type:
+/// an overflow for the integers, an infinite or not a number value for the floats.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::cost::Cost;
+///
+/// assert_eq!(Cost::checked_add(200_u8, 55), Some(255));
+/// assert_eq!(Cost::checked_add(200_u8, 56), None);
+/// assert_eq!(Cost::checked_mul_distance(3_u16, 300), Some(900));
+/// assert_eq!(Cost::checked_mul_distance(1.5_f64, 4), Some(6.0));
+/// assert_eq!(Cost::checked_add(f64::MAX, f64::MAX), None);
+/// ```
+pub trait Cost: Copy + PartialOrd + Debug {
+    fn zero() -> Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Get the cost of `distance` moves which cost `self` each.
+    fn checked_mul_distance(self, distance: usize) -> Option<Self>;
+}
+
+macro_rules! impl_integer_cost {
+    ($($integer:ty),*) => {
+        $(
+            impl Cost for $integer {
+                fn zero() -> Self {
+                    return 0;
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    return <$integer>::checked_add(self, other);
+                }
+
+                fn checked_mul_distance(self, distance: usize) -> Option<Self> {
+                    return <$integer>::try_from(distance).ok().and_then(|distance| self.checked_mul(distance));
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_float_cost {
+    ($($float:ty),*) => {
+        $(
+            impl Cost for $float {
+                fn zero() -> Self {
+                    return 0.0;
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    return Some(self + other).filter(|cost| cost.is_finite());
+                }
+
+                fn checked_mul_distance(self, distance: usize) -> Option<Self> {
+                    return Some(self * distance as $float).filter(|cost| cost.is_finite());
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_cost!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_float_cost!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_mul_distance_overflow_test() {
+        assert_eq!(Cost::checked_mul_distance(1_u8, 256), None);
+        assert_eq!(Cost::checked_mul_distance(2_i8, 64), None);
+        assert_eq!(Cost::checked_mul_distance(1_u8, 255), Some(255));
+        assert_eq!(Cost::checked_mul_distance(f32::NAN, 1), None);
+        assert_eq!(<u64 as Cost>::zero(), 0);
+    }
+}
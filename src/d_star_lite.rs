@@ -0,0 +1,497 @@
+//! # Description
+//! This is the D* Lite algorithm module.
+//! The D* Lite search from the end to the start and keep his search between two calls.
+//! When some fields of the bord change or when the start move, only the part of the search
+//! touched by the changes is computed again.
+//! [For more explainations](http://idm-lab.org/bib/abstracts/papers/aaai02b.pdf)
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+pub use crate::graph::{Field, Point};
+pub use crate::a_star::get_manhattan_distance_heuristic;
+pub use crate::path::Path;
+pub use crate::stats::SearchStats;
+use crate::graph::get_element_childs_slice;
+use crate::stats::record_road_search;
+
+const INFINITE_COST: u32 = u32::MAX;
+
+type DStarLiteKey = (u32, u32);
+
+/// Incremental resolver which keep his search state between two calls.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::d_star_lite::DStarLite;
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![1, 0, 0],
+///     vec![0, 0, 0],
+///     vec![0, 0, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(2), y: Some(2) },
+///         value: Some(2)
+///     }
+/// );
+///
+/// let mut d_star_lite = DStarLite::new(fs_example, aps_example, matrix_example.len(), start_end_fields).unwrap();
+///
+/// assert_eq!(d_star_lite.resolve().unwrap().len(), 5);
+///
+/// let new_walls = vec![
+///     Field { coordinates: Point { x: Some(1), y: Some(1) }, value: Some(-1) },
+///     Field { coordinates: Point { x: Some(1), y: Some(2) }, value: Some(-1) }
+/// ];
+///
+/// assert_eq!(d_star_lite.replan(Point { x: Some(0), y: Some(1) }, new_walls).unwrap().points, vec![
+///     Point { x: Some(0), y: Some(1) },
+///     Point { x: Some(0), y: Some(0) },
+///     Point { x: Some(1), y: Some(0) },
+///     Point { x: Some(2), y: Some(0) },
+///     Point { x: Some(2), y: Some(1) },
+///     Point { x: Some(2), y: Some(2) }
+/// ]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DStarLite {
+    fs: Vec<Field>,
+    aps: Vec<u32>,
+    matrix_size: usize,
+    values: Vec<Option<i8>>,
+    start_index: usize,
+    end_index: usize,
+    weights: Vec<u32>,
+    right_hand_sides: Vec<u32>,
+    open_keys: Vec<Option<DStarLiteKey>>,
+    open_list: BinaryHeap<Reverse<(DStarLiteKey, usize)>>,
+    open_fields: usize,
+    key_modifier: u32,
+    expanded_fields: usize
+}
+
+impl DStarLite {
+    /// Create a D* Lite resolver from a FS APS graph.
+    /// The search is only done by the first call of `resolve` or `replan`.
+    pub fn new(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field)) -> Result<Self, &'static str> {
+        if fs.is_empty() || aps.is_empty() || start_end_point == (Field::new(), Field::new()) {
+            return Err("The parameters MUST be initializes");
+        }
+
+        let (start_point, end_point) = start_end_point;
+        let fields_number = aps.len() - 1;
+        let start_index = start_point.coordinates.get_index(matrix_size)?;
+        let end_index = end_point.coordinates.get_index(matrix_size)?;
+
+        if start_index >= fields_number || end_index >= fields_number {
+            return Err("The target point cannot be found inside the matrix");
+        }
+
+        let mut values: Vec<Option<i8>> = vec![None; fields_number];
+
+        for field in fs.iter() {
+            let field_index = field.coordinates.get_index(matrix_size)?;
+
+            if field_index < fields_number {
+                values[field_index] = field.value;
+            }
+        }
+
+        values[start_index] = start_point.value;
+
+        let mut d_star_lite = Self {
+            fs,
+            aps,
+            matrix_size,
+            values,
+            start_index,
+            end_index,
+            weights: vec![INFINITE_COST; fields_number],
+            right_hand_sides: vec![INFINITE_COST; fields_number],
+            open_keys: vec![None; fields_number],
+            open_list: BinaryHeap::new(),
+            open_fields: 0,
+            key_modifier: 0,
+            expanded_fields: 0
+        };
+
+        d_star_lite.right_hand_sides[end_index] = 0;
+        let end_key = d_star_lite.calculate_key(end_index)?;
+        d_star_lite.insert_open(end_index, end_key, &mut SearchStats::new());
+
+        return Ok(d_star_lite);
+    }
+
+    /// Get the number of fields expanded by all the searches since the creation of the resolver.
+    pub fn expanded_fields(&self) -> usize {
+        return self.expanded_fields;
+    }
+
+    /// Get the shorter road from the current start to the end.
+    pub fn resolve(&mut self) -> Result<Path, &'static str> {
+        return self.resolve_with_stats(&mut SearchStats::new());
+    }
+
+    /// Get the shorter road from the current start to the end and fill the statistics of this search only.
+    /// A field added again to the open list after it has been made consistent is a reopened field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+    /// use esgi_arena_resolver_algorithms::d_star_lite::{ DStarLite, SearchStats };
+    ///
+    /// let matrix_example: Vec<Vec<i8>> = vec![
+    ///     vec![1, 0, 0],
+    ///     vec![0, 0, 0],
+    ///     vec![0, 0, 2]
+    /// ];
+    /// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+    /// let start_end_fields = (Field {
+    ///         coordinates: Point { x: Some(0), y: Some(0) },
+    ///         value: Some(1)
+    ///     },
+    ///     Field {
+    ///         coordinates: Point { x: Some(2), y: Some(2) },
+    ///         value: Some(2)
+    ///     }
+    /// );
+    /// let mut d_star_lite = DStarLite::new(fs_example, aps_example, matrix_example.len(), start_end_fields).unwrap();
+    /// let mut stats = SearchStats::new();
+    ///
+    /// d_star_lite.resolve_with_stats(&mut stats).unwrap();
+    ///
+    /// assert_eq!(stats.path_cost, Some(4));
+    /// assert_eq!(stats.nodes_expanded, d_star_lite.expanded_fields());
+    /// ```
+    pub fn resolve_with_stats(&mut self, stats: &mut SearchStats) -> Result<Path, &'static str> {
+        return record_road_search(stats, |stats| self.resolve_search(stats));
+    }
+
+    fn resolve_search(&mut self, stats: &mut SearchStats) -> Result<Path, &'static str> {
+        stats.update_open_list_size(self.open_fields);
+        self.compute_shortest_path(stats)?;
+
+        return self.get_road();
+    }
+
+    /// Move the start to the new position of the agent, apply the changed fields on the bord
+    /// and get the updated road.
+    /// The changed fields contain their coordinates and their new value, `-1` for a new wall.
+    /// The new start cannot be outside of the bord or on a wall, even a wall of the changed fields.
+    /// The new start and all the changed fields are checked before any change, so the resolver is not modified by a error.
+    pub fn replan(&mut self, new_start: Point, changed_fields: Vec<Field>) -> Result<Path, &'static str> {
+        return self.replan_with_stats(new_start, changed_fields, &mut SearchStats::new());
+    }
+
+    /// Same as the `replan` function but it's fill the statistics of the update of the changed fields and of the new search.
+    pub fn replan_with_stats(&mut self, new_start: Point, changed_fields: Vec<Field>, stats: &mut SearchStats) -> Result<Path, &'static str> {
+        return record_road_search(stats, |stats| self.replan_search(new_start, changed_fields, stats));
+    }
+
+    fn replan_search(&mut self, new_start: Point, changed_fields: Vec<Field>, stats: &mut SearchStats) -> Result<Path, &'static str> {
+        let new_start_index = new_start.get_index(self.matrix_size)?;
+
+        if new_start_index >= self.weights.len() {
+            return Err("The target point cannot be found inside the matrix");
+        }
+
+        let new_start_value = changed_fields.iter()
+            .rev()
+            .find(|changed_field| changed_field.coordinates == new_start)
+            .map_or(self.values[new_start_index], |changed_field| changed_field.value);
+
+        if new_start_value == Some(-1) {
+            return Err("The start point cannot be on a wall");
+        }
+
+        let mut changed_indexes: Vec<usize> = Vec::new();
+
+        for changed_field in changed_fields.iter() {
+            let changed_index = changed_field.coordinates.get_index(self.matrix_size)?;
+
+            if changed_index >= self.weights.len() {
+                return Err("The target point cannot be found inside the matrix");
+            }
+
+            changed_indexes.push(changed_index);
+        }
+
+        let last_start = Point::from_index(self.start_index, self.matrix_size)?;
+        self.key_modifier += get_manhattan_distance_heuristic(last_start, new_start) as u32;
+        self.start_index = new_start_index;
+
+        for (changed_field, changed_index) in changed_fields.into_iter().zip(changed_indexes) {
+            for field in self.fs.iter_mut() {
+                if field.coordinates == changed_field.coordinates {
+                    field.value = changed_field.value;
+                }
+            }
+
+            self.values[changed_index] = changed_field.value;
+            self.update_field(changed_index, stats)?;
+
+            for neighbour_index in self.neighbours(changed_index)? {
+                self.update_field(neighbour_index, stats)?;
+            }
+        }
+
+        return self.resolve_search(stats);
+    }
+
+    fn is_wall(&self, index: usize) -> bool {
+        return self.values[index] == Some(-1);
+    }
+
+    fn move_cost(&self, from_index: usize, to_index: usize) -> u32 {
+        if self.is_wall(from_index) || self.is_wall(to_index) {
+            return INFINITE_COST;
+        }
+
+        return 1;
+    }
+
+    fn neighbours(&self, index: usize) -> Result<Vec<usize>, &'static str> {
+        let mut neighbours: Vec<usize> = Vec::new();
+
+        for child in get_element_childs_slice(&self.fs, &self.aps, index)? {
+            let child_index = child.coordinates.get_index(self.matrix_size)?;
+
+            if child_index < self.weights.len() {
+                neighbours.push(child_index);
+            }
+        }
+
+        return Ok(neighbours);
+    }
+
+    fn calculate_key(&self, index: usize) -> Result<DStarLiteKey, &'static str> {
+        let smaller_weight = self.weights[index].min(self.right_hand_sides[index]);
+        let heuristic = get_manhattan_distance_heuristic(Point::from_index(self.start_index, self.matrix_size)?, Point::from_index(index, self.matrix_size)?) as u32;
+
+        return Ok((smaller_weight.saturating_add(heuristic).saturating_add(self.key_modifier), smaller_weight));
+    }
+
+    fn insert_open(&mut self, index: usize, key: DStarLiteKey, stats: &mut SearchStats) {
+        if self.open_keys[index].is_none() {
+            self.open_fields += 1;
+        }
+
+        if self.weights[index] != INFINITE_COST {
+            stats.reopened_nodes += 1;
+        }
+
+        self.open_keys[index] = Some(key);
+        self.open_list.push(Reverse((key, index)));
+        stats.nodes_generated += 1;
+        stats.update_open_list_size(self.open_fields);
+    }
+
+    fn remove_open(&mut self, index: usize) {
+        if self.open_keys[index].take().is_some() {
+            self.open_fields -= 1;
+        }
+    }
+
+    fn top_open(&mut self) -> Option<(DStarLiteKey, usize)> {
+        while let Some(Reverse((key, index))) = self.open_list.peek().cloned() {
+            if self.open_keys[index] == Some(key) {
+                return Some((key, index));
+            }
+
+            self.open_list.pop();
+        }
+
+        return None;
+    }
+
+    fn update_field(&mut self, index: usize, stats: &mut SearchStats) -> Result<(), &'static str> {
+        if index != self.end_index {
+            let mut right_hand_side = INFINITE_COST;
+
+            for neighbour_index in self.neighbours(index)? {
+                right_hand_side = right_hand_side.min(self.move_cost(index, neighbour_index).saturating_add(self.weights[neighbour_index]));
+            }
+
+            self.right_hand_sides[index] = right_hand_side;
+        }
+
+        self.remove_open(index);
+
+        if self.weights[index] != self.right_hand_sides[index] {
+            let key = self.calculate_key(index)?;
+            self.insert_open(index, key, stats);
+        }
+
+        return Ok(());
+    }
+
+    fn compute_shortest_path(&mut self, stats: &mut SearchStats) -> Result<(), &'static str> {
+        while let Some((old_key, current_index)) = self.top_open() {
+            let start_key = self.calculate_key(self.start_index)?;
+
+            if old_key >= start_key && self.right_hand_sides[self.start_index] == self.weights[self.start_index] {
+                break;
+            }
+
+            let new_key = self.calculate_key(current_index)?;
+            self.expanded_fields += 1;
+            stats.nodes_expanded += 1;
+
+            if old_key < new_key {
+                self.insert_open(current_index, new_key, stats);
+            } else if self.weights[current_index] > self.right_hand_sides[current_index] {
+                self.weights[current_index] = self.right_hand_sides[current_index];
+                self.remove_open(current_index);
+
+                for neighbour_index in self.neighbours(current_index)? {
+                    self.update_field(neighbour_index, stats)?;
+                }
+            } else {
+                self.weights[current_index] = INFINITE_COST;
+                self.update_field(current_index, stats)?;
+
+                for neighbour_index in self.neighbours(current_index)? {
+                    self.update_field(neighbour_index, stats)?;
+                }
+            }
+        }
+
+        return Ok(());
+    }
+
+    fn get_road(&self) -> Result<Path, &'static str> {
+        if self.weights[self.start_index] == INFINITE_COST {
+            return Err("It seem that it has no end to this level");
+        }
+
+        let mut current_index = self.start_index;
+        let mut start_to_end_road: Vec<Point> = vec![Point::from_index(current_index, self.matrix_size)?];
+
+        while current_index != self.end_index {
+            let mut next_field: Option<(u32, usize)> = None;
+
+            for neighbour_index in self.neighbours(current_index)? {
+                let neighbour_cost = self.move_cost(current_index, neighbour_index).saturating_add(self.weights[neighbour_index]);
+
+                if neighbour_cost != INFINITE_COST && next_field.is_none_or(|(next_cost, _)| neighbour_cost < next_cost) {
+                    next_field = Some((neighbour_cost, neighbour_index));
+                }
+            }
+
+            current_index = next_field.ok_or("It seem that it has no end to this level")?.1;
+            start_to_end_road.push(Point::from_index(current_index, self.matrix_size)?);
+
+            if start_to_end_road.len() > self.weights.len() {
+                return Err("It seem that it has no end to this level");
+            }
+        }
+
+        return Ok(Path::new(start_to_end_road));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::fs_aps_from_matrix;
+
+    #[test]
+    fn d_star_lite_remove_wall_test() {
+        let matrix = vec![
+            vec![1, -1, 2],
+            vec![0, -1, 0],
+            vec![0, 0, 0]
+        ];
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let start_end = (
+            Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(1) },
+            Field { coordinates: Point { x: Some(0), y: Some(2) }, value: Some(2) }
+        );
+        let mut d_star_lite = DStarLite::new(fs, aps, 3, start_end).unwrap();
+
+        assert_eq!(d_star_lite.resolve().unwrap().len(), 7);
+
+        let removed_wall = vec![Field { coordinates: Point { x: Some(0), y: Some(1) }, value: Some(0) }];
+
+        assert_eq!(d_star_lite.replan(Point { x: Some(0), y: Some(0) }, removed_wall).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn d_star_lite_closed_road_test() {
+        let matrix = vec![
+            vec![1, 0, 0],
+            vec![-1, -1, 0],
+            vec![2, 0, 0]
+        ];
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let start_end = (
+            Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(1) },
+            Field { coordinates: Point { x: Some(2), y: Some(0) }, value: Some(2) }
+        );
+        let mut d_star_lite = DStarLite::new(fs, aps, 3, start_end).unwrap();
+
+        assert_eq!(d_star_lite.resolve().unwrap().len(), 7);
+
+        let new_wall = vec![Field { coordinates: Point { x: Some(1), y: Some(2) }, value: Some(-1) }];
+
+        assert_eq!(d_star_lite.replan(Point { x: Some(0), y: Some(1) }, new_wall).unwrap_err(), "It seem that it has no end to this level");
+    }
+
+    #[test]
+    fn d_star_lite_invalid_new_start_test() {
+        let matrix = vec![
+            vec![1, -1, 2],
+            vec![0, -1, 0],
+            vec![0, 0, 0]
+        ];
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let start_end = (
+            Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(1) },
+            Field { coordinates: Point { x: Some(0), y: Some(2) }, value: Some(2) }
+        );
+        let mut d_star_lite = DStarLite::new(fs, aps, 3, start_end).unwrap();
+        let new_wall = vec![Field { coordinates: Point { x: Some(2), y: Some(0) }, value: Some(-1) }];
+
+        assert_eq!(d_star_lite.replan(Point { x: Some(0), y: Some(1) }, Vec::new()).unwrap_err(), "The start point cannot be on a wall");
+        assert_eq!(d_star_lite.replan(Point { x: Some(3), y: Some(0) }, Vec::new()).unwrap_err(), "The target point cannot be found inside the matrix");
+        assert_eq!(d_star_lite.replan(Point { x: Some(2), y: Some(0) }, new_wall).unwrap_err(), "The start point cannot be on a wall");
+        assert_eq!(d_star_lite.replan(Point { x: Some(1), y: Some(0) }, Vec::new()).unwrap().len(), 6);
+    }
+
+    #[test]
+    fn d_star_lite_invalid_changed_field_test() {
+        let matrix = vec![
+            vec![1, 0, 0],
+            vec![0, 0, 0],
+            vec![0, 0, 2]
+        ];
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let start_end = (
+            Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(1) },
+            Field { coordinates: Point { x: Some(2), y: Some(2) }, value: Some(2) }
+        );
+        let mut d_star_lite = DStarLite::new(fs, aps, 3, start_end).unwrap();
+        let changed_fields = vec![
+            Field { coordinates: Point { x: Some(0), y: Some(1) }, value: Some(-1) },
+            Field { coordinates: Point { x: Some(9), y: Some(1) }, value: Some(-1) }
+        ];
+
+        assert_eq!(d_star_lite.replan(Point { x: Some(1), y: Some(0) }, changed_fields).unwrap_err(), "The target point cannot be found inside the matrix");
+        assert_eq!(d_star_lite.resolve().unwrap().points, vec![
+            Point { x: Some(0), y: Some(0) },
+            Point { x: Some(0), y: Some(1) },
+            Point { x: Some(0), y: Some(2) },
+            Point { x: Some(1), y: Some(2) },
+            Point { x: Some(2), y: Some(2) }
+        ]);
+    }
+}
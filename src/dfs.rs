@@ -3,15 +3,22 @@
 //! It's a implementarion DFS algorithm for find all paths between two points of a graph.
 //! [For more explainations](https://en.wikipedia.org/wiki/Depth-first_search) 
 
+use std::time::Instant;
+
 pub use crate::graph::{Field, Point, get_element_childs_from_fs_aps};
+pub use crate::stats::SearchStats;
+pub use crate::path::Path;
+pub use crate::limits::{SearchLimits, StopReason, LimitedSearch};
 
 /// Get all paths between two Point of a graph.
+/// Each path found is added to `all_path`.
+/// It's return an error when the graph is empty, when `current_path` is empty or when the start or the end is outside of the graph.
 ///
 /// # Example
 /// 
 /// ```
 /// use esgi_arena_resolver_algorithms::graph::{Field, Point, get_element_childs_from_fs_aps};
-/// use esgi_arena_resolver_algorithms::dfs::dfs_fs_aps_recursive;
+/// use esgi_arena_resolver_algorithms::dfs::{ dfs_fs_aps_recursive, Path };
 /// 
 /// let fs_example: Vec<Field> = vec![
 /// Field {
@@ -87,41 +94,217 @@ pub use crate::graph::{Field, Point, get_element_childs_from_fs_aps};
 /// },
 /// value: Some(2)
 /// };
-/// let expected_output = vec![vec![Field { coordinates: Point { x: Some(0), y: Some(1) }, value: Some(1) },
-/// Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(0) },
-/// Field { coordinates: Point { x: Some(1), y: Some(0) }, value: Some(0) },
-/// Field { coordinates: Point { x: Some(1), y: Some(1) }, value: Some(2) }
-/// ],
-/// vec![Field { coordinates: Point { x: Some(0), y: Some(1) }, value: Some(1) },
-/// Field { coordinates: Point { x: Some(1), y: Some(1) }, value: Some(2) }
-/// ]];
+/// let expected_output = vec![Path::new(vec![Point { x: Some(0), y: Some(1) },
+/// Point { x: Some(0), y: Some(0) },
+/// Point { x: Some(1), y: Some(0) },
+/// Point { x: Some(1), y: Some(1) }
+/// ]),
+/// Path::new(vec![Point { x: Some(0), y: Some(1) },
+/// Point { x: Some(1), y: Some(1) }
+/// ])];
 /// 
-/// let mut all_path: Vec<Vec<Field>> = Vec::new();
+/// let mut all_path: Vec<Path> = Vec::new();
 /// 
-/// dfs_fs_aps_recursive(fs_example, aps_example, (start_field_example, end_field_example), 2, &mut Vec::new(), &mut vec![start_field_example], &mut all_path);
+/// dfs_fs_aps_recursive(fs_example, aps_example, (start_field_example, end_field_example), 2, &mut Vec::new(), &mut vec![start_field_example], &mut all_path).unwrap();
 /// 
 /// assert_eq!(all_path, expected_output);
 /// ```
-pub fn dfs_fs_aps_recursive(fs: Vec<Field>, aps: Vec<u32>, start_end: (Field, Field), matrix_size: usize, discovered: &mut Vec<Field>, current_path: &mut Vec<Field>, all_path: &mut Vec<Vec<Field>>) {
+pub fn dfs_fs_aps_recursive(fs: Vec<Field>, aps: Vec<u32>, start_end: (Field, Field), matrix_size: usize, discovered: &mut Vec<Field>, current_path: &mut Vec<Field>, all_path: &mut Vec<Path>) -> Result<(), &'static str> {
+    check_dfs_parameters(&fs, &aps, start_end, matrix_size)?;
+
+    if current_path.is_empty() {
+        return Err("The parameters MUST be initializes");
+    }
+
+    let mut recorder = DfsRecorder::new(aps.len() - 1, SearchLimits::new());
+
+    return dfs_fs_aps_search(fs, aps, start_end, matrix_size, discovered, current_path, all_path, &mut recorder);
+}
+
+/// Get all paths between two Point of a graph and fill the search statistics.
+/// The open list of a DFS is the current path, and a field is reopened when it's visited again by another path.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::dfs::{ dfs_all_paths_with_stats, SearchStats };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![0, 1],
+///     vec![0, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(0), y: Some(1) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(1), y: Some(1) },
+///         value: Some(2)
+///     }
+/// );
+/// let mut stats = SearchStats::new();
+///
+/// let all_path = dfs_all_paths_with_stats(fs_example, aps_example, start_end_fields, matrix_example.len(), &mut stats).unwrap();
+///
+/// assert_eq!(all_path.len(), 2);
+/// assert_eq!(stats.path_cost, Some(1));
+/// assert_eq!(stats.max_open_list_size, 4);
+/// ```
+pub fn dfs_all_paths_with_stats(fs: Vec<Field>, aps: Vec<u32>, start_end: (Field, Field), matrix_size: usize, stats: &mut SearchStats) -> Result<Vec<Path>, &'static str> {
+    check_dfs_parameters(&fs, &aps, start_end, matrix_size)?;
+
+    let start_time = Instant::now();
+    let mut recorder = DfsRecorder::new(aps.len() - 1, SearchLimits::new());
+    let mut all_path: Vec<Path> = Vec::new();
+
+    dfs_fs_aps_search(fs, aps, start_end, matrix_size, &mut Vec::new(), &mut vec![start_end.0], &mut all_path, &mut recorder)?;
+
+    *stats = recorder.stats;
+    stats.path_cost = all_path.iter().map(|path| path.cost).min();
+    stats.stop_timer(start_time);
+
+    return Ok(all_path);
+}
+
+/// Get all paths between two Point of a graph until a limit is reached.
+/// The paths found before the search stopped are returned marked as incomplete.
+/// The paths longer than `max_depth` are not searched, so the result is also incomplete when one of them is skipped.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::dfs::{ dfs_all_paths_with_limits, SearchLimits, StopReason };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![0, 1],
+///     vec![0, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(0), y: Some(1) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(1), y: Some(1) },
+///         value: Some(2)
+///     }
+/// );
+/// let mut limits = SearchLimits::new();
+///
+/// limits.max_depth = Some(1);
+///
+/// let limited_search = dfs_all_paths_with_limits(fs_example, aps_example, start_end_fields, matrix_example.len(), &limits).unwrap();
+///
+/// assert_eq!(limited_search.result.len(), 1);
+/// assert_eq!(limited_search.stop_reason, Some(StopReason::MaxDepth));
+/// ```
+pub fn dfs_all_paths_with_limits(fs: Vec<Field>, aps: Vec<u32>, start_end: (Field, Field), matrix_size: usize, limits: &SearchLimits) -> Result<LimitedSearch<Vec<Path>>, &'static str> {
+    check_dfs_parameters(&fs, &aps, start_end, matrix_size)?;
+
+    let mut recorder = DfsRecorder::new(aps.len() - 1, limits.clone());
+    let mut all_path: Vec<Path> = Vec::new();
+
+    dfs_fs_aps_search(fs, aps, start_end, matrix_size, &mut Vec::new(), &mut vec![start_end.0], &mut all_path, &mut recorder)?;
+
+    return Ok(match recorder.stop_reason {
+        Some(stop_reason) => LimitedSearch::incomplete(all_path, stop_reason),
+        None => LimitedSearch::complete(all_path)
+    });
+}
+
+/// Check that the graph is not empty and that the start and the end are inside of it.
+fn check_dfs_parameters(fs: &[Field], aps: &[u32], start_end: (Field, Field), matrix_size: usize) -> Result<(), &'static str> {
+    if fs.is_empty() || aps.is_empty() {
+        return Err("The parameters MUST be initializes");
+    }
+
+    let fields_number = aps.len() - 1;
+
+    if start_end.0.coordinates.get_index(matrix_size)? >= fields_number || start_end.1.coordinates.get_index(matrix_size)? >= fields_number {
+        return Err("The target point cannot be found inside the matrix");
+    }
+
+    return Ok(());
+}
+
+/// The statistics of a DFS with the fields already expanded by a previous path,
+/// the limits of the search and the reason why the search stopped.
+struct DfsRecorder {
+    stats: SearchStats,
+    expanded_fields: Vec<bool>,
+    limits: SearchLimits,
+    stop_reason: Option<StopReason>
+}
+
+impl DfsRecorder {
+    fn new(fields_number: usize, limits: SearchLimits) -> Self {
+        return Self {
+            stats: SearchStats::new(),
+            expanded_fields: vec![false; fields_number],
+            limits,
+            stop_reason: None
+        };
+    }
+
+    /// Check the limits before expand a field, the search stop for all the next fields once a limit is reached.
+    fn is_stopped(&mut self, paths_number: usize) -> bool {
+        if self.stop_reason.is_none() || self.stop_reason == Some(StopReason::MaxDepth) {
+            if let Some(stop_reason) = self.limits.stop_reason(self.stats.nodes_expanded, paths_number) {
+                self.stop_reason = Some(stop_reason);
+            }
+        }
+
+        return self.stop_reason.is_some_and(|stop_reason| stop_reason != StopReason::MaxDepth);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn dfs_fs_aps_search(fs: Vec<Field>, aps: Vec<u32>, start_end: (Field, Field), matrix_size: usize, discovered: &mut Vec<Field>, current_path: &mut Vec<Field>, all_path: &mut Vec<Path>, recorder: &mut DfsRecorder) -> Result<(), &'static str> {
     let (start, end) = start_end;
+
+    if recorder.is_stopped(all_path.len()) {
+        return Ok(());
+    }
+
+    if recorder.limits.is_too_deep(current_path.len().saturating_sub(1)) {
+        recorder.stop_reason = Some(StopReason::MaxDepth);
+        return Ok(());
+    }
+
     discovered.push(start);
 
+    let start_index = start.coordinates.get_index(matrix_size)?;
+
+    recorder.stats.nodes_expanded += 1;
+    recorder.stats.update_open_list_size(current_path.len());
+
+    if let Some(is_expanded) = recorder.expanded_fields.get_mut(start_index) {
+        if *is_expanded {
+            recorder.stats.reopened_nodes += 1;
+        }
+
+        *is_expanded = true;
+    }
+
     let current_field_discover_index = discovered.len() - 1;
 
     if start == end {
-        all_path.push(current_path.to_owned());
-        return;
+        all_path.push(Path::new(current_path.iter().map(|field| field.coordinates).collect()));
+        return Ok(());
     }
 
-    for child in get_element_childs_from_fs_aps(fs.clone(), aps.clone(), start.coordinates.get_index(matrix_size).unwrap()).unwrap() {
+    for child in get_element_childs_from_fs_aps(fs.clone(), aps.clone(), start_index)? {
         let mut is_discover = false;
         
-        if child.value.unwrap() == -1 {
+        if child.value == Some(-1) {
             continue;
         }
 
-        for discovered_field in discovered.to_owned() {
-            if discovered_field == child {
+        for discovered_field in discovered.iter() {
+            if *discovered_field == child {
                 is_discover = true;
             }
         }
@@ -131,13 +314,15 @@ pub fn dfs_fs_aps_recursive(fs: Vec<Field>, aps: Vec<u32>, start_end: (Field, Fi
         }
 
         current_path.push(child);
+        recorder.stats.nodes_generated += 1;
         let current_field_index_path = current_path.len() - 1;
-        dfs_fs_aps_recursive(fs.clone(), aps.clone(), (child, end), matrix_size, &mut discovered.clone(), &mut current_path.clone(), all_path);
+        dfs_fs_aps_search(fs.clone(), aps.clone(), (child, end), matrix_size, &mut discovered.clone(), &mut current_path.clone(), all_path, recorder)?;
         current_path.remove(current_field_index_path);
     }
 
     discovered.remove(current_field_discover_index);
 
+    return Ok(());
 }
 
 #[cfg(test)]
@@ -329,10 +514,8 @@ mod test {
         }];
 
         let fs_example: Vec<Field> = matrix_first_line.into_iter()
-            .chain(matrix_second_line
-                .into_iter())
-            .chain(matrix_third_line
-                .into_iter())
+            .chain(matrix_second_line)
+            .chain(matrix_third_line)
             .collect();
 
 
@@ -418,19 +601,19 @@ mod test {
             },
             value: Some(2)
         };
-        let expected_output = vec![vec![Field { coordinates: Point { x: Some(0), y: Some(1) }, value: Some(1) },
-        Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(0) },
-        Field { coordinates: Point { x: Some(1), y: Some(0) }, value: Some(0) },
-        Field { coordinates: Point { x: Some(1), y: Some(1) }, value: Some(2) }
-        ],
-        vec![Field { coordinates: Point { x: Some(0), y: Some(1) }, value: Some(1) },
-        Field { coordinates: Point { x: Some(1), y: Some(1) }, value: Some(2) }
-        ]];
+        let expected_output = vec![Path::new(vec![Point { x: Some(0), y: Some(1) },
+        Point { x: Some(0), y: Some(0) },
+        Point { x: Some(1), y: Some(0) },
+        Point { x: Some(1), y: Some(1) }
+        ]),
+        Path::new(vec![Point { x: Some(0), y: Some(1) },
+        Point { x: Some(1), y: Some(1) }
+        ])];
         
 
-        let mut all_path: Vec<Vec<Field>> = Vec::new();
+        let mut all_path: Vec<Path> = Vec::new();
 
-        dfs_fs_aps_recursive(fs_example, aps_example, (start_field_example, end_field_example), 2, &mut Vec::new(), &mut vec![start_field_example], &mut all_path);
+        dfs_fs_aps_recursive(fs_example, aps_example, (start_field_example, end_field_example), 2, &mut Vec::new(), &mut vec![start_field_example], &mut all_path).unwrap();
 
         assert_eq!(all_path, expected_output);
         
@@ -457,21 +640,33 @@ mod test {
             }
         );
 
-        let expected_output = vec![vec![Field { coordinates: Point { x: Some(2), y: Some(0) }, value: Some(1) },
-        Field { coordinates: Point { x: Some(2), y: Some(1) }, value: Some(0) },
-        Field { coordinates: Point { x: Some(2), y: Some(2) }, value: Some(0) },
-        Field { coordinates: Point { x: Some(1), y: Some(2) }, value: Some(0) },
-        Field { coordinates: Point { x: Some(0), y: Some(2) }, value: Some(0) },
-        Field { coordinates: Point { x: Some(0), y: Some(1) }, value: Some(0) },
-        Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(2) }]];
+        let expected_output = vec![Path::new(vec![Point { x: Some(2), y: Some(0) },
+        Point { x: Some(2), y: Some(1) },
+        Point { x: Some(2), y: Some(2) },
+        Point { x: Some(1), y: Some(2) },
+        Point { x: Some(0), y: Some(2) },
+        Point { x: Some(0), y: Some(1) },
+        Point { x: Some(0), y: Some(0) }])];
         
-        let mut all_path: Vec<Vec<Field>> = Vec::new();
+        let mut all_path: Vec<Path> = Vec::new();
 
-        dfs_fs_aps_recursive(fs_example, aps_example, start_end, 3, &mut Vec::new(), &mut vec![start_end.0], &mut all_path);
+        dfs_fs_aps_recursive(fs_example, aps_example, start_end, 3, &mut Vec::new(), &mut vec![start_end.0], &mut all_path).unwrap();
 
         assert_eq!(all_path, expected_output);
         
     }
 
-
-}
\ No newline at end of file
+    #[test]
+    fn dfs_fs_aps_recursive_invalid_parameters_test() {
+        let (_, fs_example, aps_example) = testing_data_heavy_matrix();
+        let start_field = Field { coordinates: Point { x: Some(2), y: Some(0) }, value: Some(1) };
+        let outside_field = Field { coordinates: Point { x: Some(3), y: Some(0) }, value: Some(2) };
+        let mut all_path: Vec<Path> = Vec::new();
+
+        assert!(dfs_fs_aps_recursive(fs_example.clone(), aps_example.clone(), (start_field, outside_field), 3, &mut Vec::new(), &mut vec![start_field], &mut all_path).is_err());
+        assert!(dfs_fs_aps_recursive(fs_example.clone(), aps_example.clone(), (start_field, start_field), 3, &mut Vec::new(), &mut Vec::new(), &mut all_path).is_err());
+        assert!(dfs_all_paths_with_stats(Vec::new(), aps_example.clone(), (start_field, start_field), 3, &mut SearchStats::new()).is_err());
+        assert!(dfs_all_paths_with_limits(fs_example, aps_example, (start_field, start_field), 0, &SearchLimits::new()).is_err());
+        assert!(all_path.is_empty());
+    }
+}
@@ -0,0 +1,252 @@
+//! # Description
+//! This is the all-pairs distance table module, for the small bords with many distance queries.
+//! A BFS from each field gives the distance and the next field to follow between all the pairs of fields,
+//! then each query is a lookup in the table.
+//! The table can be saved in a file and loaded again without computing it.
+
+use std::fs;
+
+pub use crate::graph::{Field, Point};
+pub use crate::path::Path;
+use crate::bfs::bfs_search;
+
+/// The first bytes of a saved distance table, with the version of the format.
+const FILE_HEADER: &[u8; 4] = b"DTB1";
+/// The value saved for no distance or no next field.
+const NONE_VALUE: u32 = u32::MAX;
+
+/// Distances and next fields between all the pairs of fields of a bord.
+/// The values for the road from `from` to `to` are at the index `from * fields_number + to`.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::distance_table::DistanceTable;
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![1, -1, 0],
+///     vec![0, 0, 0],
+///     vec![-1, -1, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_point = Point { x: Some(0), y: Some(0) };
+/// let end_point = Point { x: Some(2), y: Some(2) };
+///
+/// let distance_table = DistanceTable::new(fs_example, aps_example, matrix_example.len()).unwrap();
+///
+/// assert_eq!(distance_table.distance(start_point, end_point), Some(4));
+/// assert_eq!(distance_table.next_hop(start_point, end_point), Some(Point { x: Some(1), y: Some(0) }));
+/// assert_eq!(distance_table.road(start_point, end_point).unwrap().len(), 5);
+/// assert_eq!(distance_table.distance(start_point, Point { x: Some(0), y: Some(1) }), None);
+/// assert_eq!(DistanceTable::from_bytes(&distance_table.to_bytes()).unwrap(), distance_table);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistanceTable {
+    matrix_size: usize,
+    fields_number: usize,
+    distances: Vec<Option<usize>>,
+    next_hops: Vec<Option<usize>>
+}
+
+impl DistanceTable {
+    /// Compute the table with a BFS from each field.
+    pub fn new(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize) -> Result<Self, &'static str> {
+        if fs.is_empty() || aps.is_empty() {
+            return Err("The parameters MUST be initializes");
+        }
+
+        let fields_number = aps.len() - 1;
+        let mut distances: Vec<Option<usize>> = Vec::with_capacity(fields_number * fields_number);
+        let mut next_hops: Vec<Option<usize>> = Vec::with_capacity(fields_number * fields_number);
+
+        for start_index in 0..fields_number {
+            let (start_distances, parents) = bfs_search(&fs, &aps, matrix_size, start_index, None, &|_, _| true)?;
+            let mut start_next_hops: Vec<Option<usize>> = vec![None; fields_number];
+            let mut reached_indexes: Vec<usize> = (0..fields_number).filter(|index| start_distances[*index].is_some()).collect();
+
+            reached_indexes.sort_by_key(|index| start_distances[*index]);
+
+            for index in reached_indexes.into_iter().skip(1) {
+                start_next_hops[index] = match parents[index] {
+                    Some(parent_index) if parent_index == start_index => Some(index),
+                    Some(parent_index) => start_next_hops[parent_index],
+                    None => None
+                };
+            }
+
+            distances.extend(start_distances);
+            next_hops.extend(start_next_hops);
+        }
+
+        return Ok(Self {
+            matrix_size,
+            fields_number,
+            distances,
+            next_hops
+        });
+    }
+
+    fn get_table_index(&self, from_point: Point, to_point: Point) -> Option<usize> {
+        let from_index = from_point.get_index(self.matrix_size).ok()?;
+        let to_index = to_point.get_index(self.matrix_size).ok()?;
+
+        if from_index >= self.fields_number || to_index >= self.fields_number {
+            return None;
+        }
+
+        return Some(from_index * self.fields_number + to_index);
+    }
+
+    /// Get the number of moves from a point to another one, `None` when there is no road or when a point is outside of the bord.
+    pub fn distance(&self, from_point: Point, to_point: Point) -> Option<usize> {
+        return self.get_table_index(from_point, to_point).and_then(|table_index| self.distances[table_index]);
+    }
+
+    /// Get the first field to go on from a point to another one, `None` when there is no road or when both points are the same.
+    pub fn next_hop(&self, from_point: Point, to_point: Point) -> Option<Point> {
+        let next_index = self.get_table_index(from_point, to_point).and_then(|table_index| self.next_hops[table_index])?;
+
+        return Point::from_index(next_index, self.matrix_size).ok();
+    }
+
+    /// Rebuild the shorter road between two points by following the next fields.
+    pub fn road(&self, from_point: Point, to_point: Point) -> Result<Path, &'static str> {
+        let distance = match self.get_table_index(from_point, to_point) {
+            Some(table_index) => self.distances[table_index].ok_or("It seem that it has no end to this level")?,
+            None => return Err("The target point cannot be found inside the matrix")
+        };
+        let mut points: Vec<Point> = vec![from_point];
+
+        while points.len() <= distance {
+            points.push(self.next_hop(*points.last().unwrap(), to_point).ok_or("It seem that it has no end to this level")?);
+        }
+
+        return Ok(Path::new(points));
+    }
+
+    /// Get the table as bytes: the header, the matrix size, the number of fields,
+    /// then the distance and the next field of each pair, all the numbers are little endian `u32`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = FILE_HEADER.to_vec();
+
+        bytes.extend((self.matrix_size as u32).to_le_bytes());
+        bytes.extend((self.fields_number as u32).to_le_bytes());
+
+        for (distance, next_hop) in self.distances.iter().zip(self.next_hops.iter()) {
+            bytes.extend(distance.map_or(NONE_VALUE, |distance| distance as u32).to_le_bytes());
+            bytes.extend(next_hop.map_or(NONE_VALUE, |next_hop| next_hop as u32).to_le_bytes());
+        }
+
+        return bytes;
+    }
+
+    /// Read a table from the bytes given by `to_bytes`.
+    /// The bytes are refused when their size does not match the number of fields,
+    /// or when a next field does not go one move closer to the target, so `road` always ends.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, &'static str> {
+        let numbers: Vec<u32> = match bytes.strip_prefix(FILE_HEADER) {
+            Some(table_bytes) if table_bytes.len() % 4 == 0 => table_bytes.chunks(4).map(|number_bytes| u32::from_le_bytes([number_bytes[0], number_bytes[1], number_bytes[2], number_bytes[3]])).collect(),
+            _ => return Err("The distance table bytes are not valid")
+        };
+
+        if numbers.len() < 2 {
+            return Err("The distance table bytes are not valid");
+        }
+
+        let matrix_size = numbers[0] as usize;
+        let fields_number = numbers[1] as usize;
+
+        if fields_number.checked_mul(fields_number).and_then(|pairs_number| pairs_number.checked_mul(2)) != Some(numbers.len() - 2) {
+            return Err("The distance table bytes are not valid");
+        }
+
+        let read_value = |number: u32| -> Option<usize> {
+            return if number == NONE_VALUE { None } else { Some(number as usize) };
+        };
+        let distances: Vec<Option<usize>> = numbers[2..].chunks(2).map(|pair_numbers| read_value(pair_numbers[0])).collect();
+        let next_hops: Vec<Option<usize>> = numbers[2..].chunks(2).map(|pair_numbers| read_value(pair_numbers[1])).collect();
+
+        let distance_table = Self {
+            matrix_size,
+            fields_number,
+            distances,
+            next_hops
+        };
+
+        if !distance_table.has_decreasing_next_hops() {
+            return Err("The distance table bytes are not valid");
+        }
+
+        return Ok(distance_table);
+    }
+
+    fn has_decreasing_next_hops(&self) -> bool {
+        for table_index in 0..self.distances.len() {
+            let (from_index, to_index) = (table_index / self.fields_number, table_index % self.fields_number);
+
+            let is_valid = match (self.distances[table_index], self.next_hops[table_index]) {
+                (Some(0), None) => from_index == to_index,
+                (Some(distance), Some(next_index)) if distance > 0 && next_index < self.fields_number => {
+                    self.distances[next_index * self.fields_number + to_index] == Some(distance - 1)
+                },
+                (None, None) => true,
+                _ => false
+            };
+
+            if !is_valid {
+                return false;
+            }
+        }
+
+        return true;
+    }
+
+    /// Save the table bytes in a file.
+    pub fn save(&self, file_path: &str) -> Result<(), &'static str> {
+        return fs::write(file_path, self.to_bytes()).map_err(|_| "The distance table file cannot be written");
+    }
+
+    /// Load a table saved with `save`, with the same checks as `from_bytes`.
+    pub fn load(file_path: &str) -> Result<Self, &'static str> {
+        let bytes = fs::read(file_path).map_err(|_| "The distance table file cannot be read")?;
+
+        return Self::from_bytes(&bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::fs_aps_from_matrix;
+
+    #[test]
+    fn from_bytes_invalid_test() {
+        let (fs, aps) = fs_aps_from_matrix(vec![vec![1, 0], vec![0, 2]]).unwrap();
+        let bytes = DistanceTable::new(fs, aps, 2).unwrap().to_bytes();
+
+        assert!(DistanceTable::from_bytes(&bytes).is_ok());
+        assert!(DistanceTable::from_bytes(&bytes[..bytes.len() - 4]).is_err());
+        assert!(DistanceTable::from_bytes(&bytes[1..]).is_err());
+        assert!(DistanceTable::from_bytes(b"DTB1").is_err());
+        assert_eq!(DistanceTable::from_bytes(&[b"DTB1".as_slice(), &[0; 8]].concat()).map(|distance_table| distance_table.fields_number), Ok(0));
+    }
+
+    #[test]
+    fn from_bytes_cyclic_next_hops_test() {
+        let (fs, aps) = fs_aps_from_matrix(vec![vec![1, 0], vec![0, 2]]).unwrap();
+        let distance_table = DistanceTable::new(fs, aps, 2).unwrap();
+        let mut cyclic_table = distance_table.clone();
+
+        cyclic_table.next_hops[3] = Some(0);
+
+        assert!(DistanceTable::from_bytes(&cyclic_table.to_bytes()).is_err());
+
+        let mut wrong_distance_table = distance_table.clone();
+
+        wrong_distance_table.distances[3] = Some(u32::MAX as usize - 1);
+
+        assert!(DistanceTable::from_bytes(&wrong_distance_table.to_bytes()).is_err());
+        assert_eq!(DistanceTable::from_bytes(&distance_table.to_bytes()), Ok(distance_table));
+    }
+}
@@ -0,0 +1,189 @@
+//! # Description
+//! This is the flow field module, for the bords where many bots go to the same end points.
+//! The distance map is computed once from all the end points, each move costs 1
+//! so the reverse Dijkstra is a BFS started from all the end points at the same time.
+//! Then each field knows the move to do for get closer to the nearest end point,
+//! and any bot can follow these moves without his own search.
+
+use std::collections::VecDeque;
+
+pub use crate::graph::{Field, Point};
+pub use crate::path::{Path, Move};
+use crate::graph::get_element_childs_slice;
+
+/// The distance to the nearest end point and the best move of each field of a bord.
+/// The values are stored by the index of the field inside the flatten matrix.
+/// The end points have no move, the walls and the fields without road to an end point have no distance and no move.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlowField {
+    matrix_size: usize,
+    distances: Vec<Option<usize>>,
+    directions: Vec<Option<Move>>
+}
+
+impl FlowField {
+    fn get_field_index(&self, point: Point) -> Option<usize> {
+        return point.get_index(self.matrix_size).ok().filter(|index| *index < self.distances.len());
+    }
+
+    /// Get the distances of all the fields, by index of the field inside the flatten matrix.
+    pub fn distances(&self) -> &[Option<usize>] {
+        return &self.distances;
+    }
+
+    pub fn distance(&self, point: Point) -> Option<usize> {
+        return self.get_field_index(point).and_then(|index| self.distances[index]);
+    }
+
+    /// Get the move to do on a field for get closer to the nearest end point.
+    pub fn direction(&self, point: Point) -> Option<Move> {
+        return self.get_field_index(point).and_then(|index| self.directions[index]);
+    }
+
+    /// Get the field reached by following the move of a field.
+    pub fn next_point(&self, point: Point) -> Option<Point> {
+        let (x, y) = (point.x?, point.y?);
+
+        return match self.direction(point)? {
+            Move::Up => Some(Point { x: Some(x - 1), y: Some(y) }),
+            Move::Down => Some(Point { x: Some(x + 1), y: Some(y) }),
+            Move::Left => Some(Point { x: Some(x), y: Some(y - 1) }),
+            Move::Right => Some(Point { x: Some(x), y: Some(y + 1) })
+        };
+    }
+
+    /// Follow the moves from a start point to the nearest end point.
+    pub fn road_from(&self, start_point: Point) -> Result<Path, &'static str> {
+        let distance = match self.get_field_index(start_point) {
+            Some(index) => self.distances[index].ok_or("It seem that it has no end to this level")?,
+            None => return Err("The target point cannot be found inside the matrix")
+        };
+        let mut points: Vec<Point> = vec![start_point];
+
+        while points.len() <= distance {
+            points.push(self.next_point(*points.last().unwrap()).ok_or("It seem that it has no end to this level")?);
+        }
+
+        return Ok(Path::new(points));
+    }
+}
+
+/// Flow field resolver function.
+/// It's compute the distance from each field to the nearest end point and the move to do on each field.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::flow_field::{ flow_field_resolver, Move };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![2, 0, 0],
+///     vec![-1, -1, 0],
+///     vec![0, 0, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let end_fields = vec![Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(2)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(2), y: Some(2) },
+///         value: Some(2)
+///     }
+/// ];
+///
+/// let flow_field = flow_field_resolver(fs_example, aps_example, matrix_example.len(), end_fields).unwrap();
+///
+/// assert_eq!(flow_field.distances(), &[Some(0), Some(1), Some(2), None, None, Some(1), Some(2), Some(1), Some(0)]);
+/// assert_eq!(flow_field.direction(Point { x: Some(0), y: Some(1) }), Some(Move::Left));
+/// assert_eq!(flow_field.direction(Point { x: Some(2), y: Some(0) }), Some(Move::Right));
+/// assert_eq!(flow_field.road_from(Point { x: Some(2), y: Some(0) }).unwrap().cost, 2);
+/// ```
+pub fn flow_field_resolver(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, end_points: Vec<Field>) -> Result<FlowField, &'static str> {
+    if fs.is_empty() || aps.is_empty() || end_points.is_empty() {
+        return Err("The parameters MUST be initializes");
+    }
+
+    let fields_number = aps.len() - 1;
+    let mut distances: Vec<Option<usize>> = vec![None; fields_number];
+    let mut queue: VecDeque<usize> = VecDeque::new();
+
+    for end_point in end_points.iter() {
+        let end_index = end_point.coordinates.get_index(matrix_size)?;
+
+        if end_index >= fields_number {
+            return Err("The target point cannot be found inside the matrix");
+        }
+
+        if distances[end_index].is_none() {
+            distances[end_index] = Some(0);
+            queue.push_back(end_index);
+        }
+    }
+
+    while let Some(current_index) = queue.pop_front() {
+        let current_distance = distances[current_index].unwrap_or(0);
+
+        for child in get_element_childs_slice(&fs, &aps, current_index)? {
+            let child_index = child.coordinates.get_index(matrix_size)?;
+
+            if child.value == Some(-1) || child_index >= fields_number || distances[child_index].is_some() {
+                continue;
+            }
+
+            distances[child_index] = Some(current_distance + 1);
+            queue.push_back(child_index);
+        }
+    }
+
+    let mut directions: Vec<Option<Move>> = vec![None; fields_number];
+
+    for (index, direction) in directions.iter_mut().enumerate() {
+        let distance = match distances[index] {
+            Some(distance) if distance > 0 => distance,
+            _ => continue
+        };
+
+        for child in get_element_childs_slice(&fs, &aps, index)? {
+            let child_index = child.coordinates.get_index(matrix_size)?;
+
+            if child.value != Some(-1) && distances.get(child_index).cloned().flatten() == Some(distance - 1) {
+                *direction = Some(Move::between(Point::from_index(index, matrix_size)?, child.coordinates)?);
+                break;
+            }
+        }
+    }
+
+    return Ok(FlowField {
+        matrix_size,
+        distances,
+        directions
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::fs_aps_from_matrix;
+
+    #[test]
+    fn flow_field_resolver_no_road_test() {
+        let matrix = vec![
+            vec![2, -1, 0],
+            vec![-1, 0, 0],
+            vec![0, 0, 1]
+        ];
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let end_fields = vec![Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(2) }];
+
+        let flow_field = flow_field_resolver(fs, aps, 3, end_fields).unwrap();
+
+        assert_eq!(flow_field.distance(Point { x: Some(2), y: Some(2) }), None);
+        assert_eq!(flow_field.direction(Point { x: Some(0), y: Some(0) }), None);
+        assert_eq!(flow_field.next_point(Point { x: Some(0), y: Some(0) }), None);
+        assert!(flow_field.road_from(Point { x: Some(2), y: Some(2) }).is_err());
+        assert!(flow_field.road_from(Point { x: Some(5), y: Some(5) }).is_err());
+        assert_eq!(flow_field.road_from(Point { x: Some(0), y: Some(0) }).unwrap().len(), 1);
+    }
+}
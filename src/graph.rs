@@ -39,6 +39,12 @@ impl Field {
     }
 }
 
+impl Default for Field {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
 /// Point is a organisational structure.
 /// It's use group x and y index from the matrix.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -71,11 +77,47 @@ impl Point {
     /// assert_eq!(point_test.get_index(matrix_size).unwrap(), 7);
     /// ```
     pub fn get_index(&self, matrix_size: usize) -> Result<usize, &'static str> {
-        if self.x.is_none() || self.y.is_none() || matrix_size == 0 {
-            return Err("The x and y must be declare for get a index");
+        let (x, y) = match (self.x, self.y) {
+            (Some(x), Some(y)) if matrix_size != 0 => (x, y),
+            _ => return Err("The x and y must be declare for get a index")
+        };
+
+        if y >= matrix_size {
+            return Err("The target point cannot be found inside the matrix");
+        }
+
+        return x.checked_mul(matrix_size).and_then(|line_index| line_index.checked_add(y)).ok_or("The target point cannot be found inside the matrix");
+    }
+
+    /// Build back a Point from his index in the flatten matrix.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// use esgi_arena_resolver_algorithms::graph::Point;
+    /// 
+    /// let matrix_size: usize = 5;
+    /// 
+    /// assert_eq!(Point::from_index(7, matrix_size).unwrap(), Point {
+    ///     x: Some(1),
+    ///     y: Some(2)
+    /// });
+    /// ```
+    pub fn from_index(index: usize, matrix_size: usize) -> Result<Self, &'static str> {
+        if matrix_size == 0 {
+            return Err("The matrix size cannot be equals to 0");
         }
 
-        return Ok(self.x.unwrap() * matrix_size + self.y.unwrap());
+        return Ok(Self {
+            x: Some(index / matrix_size),
+            y: Some(index % matrix_size)
+        });
+    }
+}
+
+impl Default for Point {
+    fn default() -> Self {
+        return Self::new();
     }
 }
 
@@ -130,10 +172,8 @@ pub fn get_start_to_end_points(matrix_bord: Vec<Vec<i8>>) -> Result<(Point, Poin
     let matrix_ligne_number = matrix_bord.len();
     let mut result_points: (Point, Point) = (Point::new(), Point::new());
 
-    let message_option = bord_is_well_form(matrix_bord.as_slice());
-
-    if message_option.is_some() {
-        return Err(message_option.unwrap());
+    if let Some(message) = bord_is_well_form(matrix_bord.as_slice()) {
+        return Err(message);
     }
 
     for (i, matrix_line) in matrix_bord.iter().enumerate() {
@@ -203,10 +243,8 @@ pub fn get_start_to_end_points_multi_roads(matrix_bord: Vec<Vec<i8>>) -> Result<
     let matrix_ligne_number = matrix_bord.len();
     let mut result_points: (Point, Vec<Point>) = (Point::new(), Vec::new());
 
-    let message_option = bord_is_well_form(matrix_bord.as_slice());
-
-    if message_option.is_some() {
-        return Err(message_option.unwrap());
+    if let Some(message) = bord_is_well_form(matrix_bord.as_slice()) {
+        return Err(message);
     }
 
     for (i, matrix_line) in matrix_bord.iter().enumerate() {
@@ -311,10 +349,12 @@ pub fn get_start_to_end_points_multi_roads(matrix_bord: Vec<Vec<i8>>) -> Result<
 /// assert_eq!(fs_aps_from_matrix(sample_data).unwrap(), (expect_fs, expect_aps));
 /// ```
 pub fn fs_aps_from_matrix(matrix: Vec<Vec<i8>>) -> Result<(Vec<Field>, Vec<u32>), &'static str> {
-    let message_option = bord_is_well_form(matrix.as_slice());
+    if let Some(message) = bord_is_well_form(matrix.as_slice()) {
+        return Err(message);
+    }
 
-    if message_option.is_some() {
-        return Err(message_option.unwrap());
+    if matrix.iter().any(|matrix_line| matrix_line.len() != matrix.len()) {
+        return Err("The number of colunms should be equals to the number of lines");
     }
 
     let mut fs: Vec<Field> = Vec::new();
@@ -324,7 +364,7 @@ pub fn fs_aps_from_matrix(matrix: Vec<Vec<i8>>) -> Result<(Vec<Field>, Vec<u32>)
         for index in 0..matrix.len() {
             let mut current_aps_index: u32 = 0;
 
-            if index as i8 - 1 >= 0 {
+            if index >= 1 {
                 fs.push(Field {
                     coordinates: Point {
                         x: Some(line_index),
@@ -348,7 +388,7 @@ pub fn fs_aps_from_matrix(matrix: Vec<Vec<i8>>) -> Result<(Vec<Field>, Vec<u32>)
                     current_aps_index += 1;
             }
 
-            if line_index as i8 - 1 >= 0 {
+            if line_index >= 1 {
                 fs.push(Field {
                     coordinates: Point {
                         x: Some(line_index - 1),
@@ -410,40 +450,23 @@ pub fn fs_aps_from_matrix(matrix: Vec<Vec<i8>>) -> Result<(Vec<Field>, Vec<u32>)
 pub fn get_field_by_index(matrix_bord: Vec<Vec<i8>>, index: Point) -> Result<Field, &'static str> {
     let matrix_ligne_number = matrix_bord.len();
 
-    let message_option = bord_is_well_form(matrix_bord.as_slice());
-
-    if message_option.is_some() {
-        return Err(message_option.unwrap());
+    if let Some(message) = bord_is_well_form(matrix_bord.as_slice()) {
+        return Err(message);
     }
 
-    let mut result_field: Field = Field::new();
-
-    for (i, matrix_line) in matrix_bord.iter().enumerate() {
-        if matrix_line.len() != matrix_ligne_number {
-            return Err("The number of colunms should be equals to the number of lines");
-        }
-
-        if i != index.x.unwrap() {
-            continue;
-        }
-
-        result_field.coordinates.x = Some(i);
-
-        for (y, value) in matrix_line.iter().enumerate() {
-            if y != index.y.unwrap() {
-                continue;
-            }
-
-            result_field.coordinates.y = Some(y);
-            result_field.value = Some(value.clone());
-        } 
+    if matrix_bord.iter().any(|matrix_line| matrix_line.len() != matrix_ligne_number) {
+        return Err("The number of colunms should be equals to the number of lines");
     }
 
-    if index.x.is_none() || index.y.is_none() {
-        return Err("The target point cannot be found inside the matrix");
-    }
+    let value = match (index.x, index.y) {
+        (Some(x), Some(y)) => matrix_bord.get(x).and_then(|matrix_line| matrix_line.get(y)).cloned(),
+        _ => None
+    };
 
-    return Ok(result_field);
+    return match value {
+        Some(value) => Ok(Field { coordinates: index, value: Some(value) }),
+        None => Err("The target point cannot be found inside the matrix")
+    };
 }
 
 /// Get all childs from a target element.
@@ -537,21 +560,45 @@ pub fn get_field_by_index(matrix_bord: Vec<Vec<i8>>, index: Point) -> Result<Fie
 /// ]);
 /// ```
 pub fn get_element_childs_from_fs_aps(fs: Vec<Field>, aps: Vec<u32>, index: usize) -> Result<Vec<Field>, &'static str> {
-    if index >= aps.len() {
+    if index >= aps.len().saturating_sub(1) {
         return Err("The index cannot be bigger than the size of APS vector");
     }
 
-    let fs_start_index = aps.get(index).unwrap().clone() as usize;
-    let fs_end_index = aps.get(index + 1).unwrap().clone() as usize;
+    let fs_start_index = aps[index] as usize;
+    let fs_end_index = aps[index + 1] as usize;
 
     return Ok(fs.iter()
         .enumerate()
         .filter(|(index, _)| index >= &fs_start_index && index < &fs_end_index)
-        .map(|(_, element)| element.clone())
+        .map(|(_, element)| *element)
         .collect());
 
 }
 
+/// Get the number of fields of a square bord, the APS vector MUST have one range of childs by field.
+pub(crate) fn get_fields_number(aps: &[u32], matrix_size: usize) -> Result<usize, &'static str> {
+    let fields_number = aps.len().saturating_sub(1);
+
+    if matrix_size.checked_mul(matrix_size) != Some(fields_number) {
+        return Err("The matrix size does not match the APS vector");
+    }
+
+    return Ok(fields_number);
+}
+
+/// Same as `get_element_childs_from_fs_aps` but borrow the FS instead of copying it.
+/// It's use by the resolvers which read the childs of many elements.
+pub(crate) fn get_element_childs_slice<'a>(fs: &'a [Field], aps: &[u32], index: usize) -> Result<&'a [Field], &'static str> {
+    if index >= aps.len().saturating_sub(1) {
+        return Err("The index cannot be bigger than the size of APS vector");
+    }
+
+    let fs_start_index = aps[index] as usize;
+    let fs_end_index = aps[index + 1] as usize;
+
+    return fs.get(fs_start_index..fs_end_index).ok_or("The APS vector does not match the FS vector");
+}
+
 /// Remove a target end point from a aps.
 /// The remove only change the value of the Field to 0.
 /// 
@@ -690,9 +737,9 @@ pub fn get_element_childs_from_fs_aps(fs: Vec<Field>, aps: Vec<u32>, index: usiz
 /// 
 /// assert_eq!(sample_fs, expected_fs);
 /// ```
-pub fn remove_end_point_from_aps(fs: &mut Vec<Field>, end_point: Point) -> Vec<Field> {
+pub fn remove_end_point_from_aps(fs: &mut [Field], end_point: Point) -> Vec<Field> {
     for i in 0..fs.len() {
-        let mut current_field = fs.get_mut(i).unwrap();
+        let current_field = fs.get_mut(i).unwrap();
         
         if current_field.coordinates == end_point {
             current_field.value = Some(0);
@@ -877,4 +924,26 @@ mod test {
 
             assert_eq!(remove_end_point_from_aps(&mut fs_example, end_point_to_purge), fs_expected);
         }
-}
\ No newline at end of file
+
+        #[test]
+        fn get_index_outside_matrix_test() {
+            assert!(Point { x: Some(1), y: None }.get_index(5).is_err());
+            assert!(Point { x: Some(1), y: Some(5) }.get_index(5).is_err());
+            assert!(Point { x: Some(usize::MAX), y: Some(1) }.get_index(5).is_err());
+            assert_eq!(Point { x: Some(5), y: Some(4) }.get_index(5), Ok(29));
+        }
+
+        #[test]
+        fn get_field_by_index_outside_matrix_test() {
+            let sample_data: Vec<Vec<i8>> = vec![
+                vec![1, 0],
+                vec![0, 2]
+            ];
+
+            assert!(get_field_by_index(sample_data.clone(), Point { x: Some(2), y: Some(0) }).is_err());
+            assert!(get_field_by_index(sample_data.clone(), Point { x: None, y: Some(0) }).is_err());
+            assert!(get_field_by_index(vec![vec![1, 0], vec![2]], Point { x: Some(0), y: Some(0) }).is_err());
+            assert!(get_element_childs_from_fs_aps(Vec::new(), vec![0, 2], 1).is_err());
+            assert!(fs_aps_from_matrix(vec![vec![1, 0, 0], vec![0, 2]]).is_err());
+        }
+}
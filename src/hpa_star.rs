@@ -0,0 +1,570 @@
+//! # Description
+//! This is the Hierarchical Path-Finding A* (HPA*) module, for the big bords with many searches.
+//! The bord is cut in square clusters, the entrances between two clusters are the abstract nodes
+//! and the distances between the entrances of a cluster are computed once.
+//! A search runs on this small abstract graph, then each abstract move is refined in a road of fields.
+//! The roads are close to the shorter ones but not always the shorter ones.
+//! [For more explainations](https://webdocs.cs.ualberta.ca/~mmueller/ps/hpastar.pdf)
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+pub use crate::graph::{Field, Point};
+pub use crate::path::Path;
+pub use crate::stats::SearchStats;
+use crate::graph::{get_element_childs_slice, get_fields_number};
+use crate::bfs::{bfs_search_with_stats, get_index_road_from_parents, BfsTree};
+use crate::stats::record_road_search;
+
+/// An open border segment shorter than this length has one entrance in his middle,
+/// a longer segment has one entrance at each side.
+const MAX_SINGLE_ENTRANCE_LENGTH: usize = 6;
+
+/// The abstract graph of a bord used by the HPA* search.
+/// It's built once with `HierarchicalGraph::new`, then `resolve` can be called for each search
+/// and `update_field` rebuilds only the clusters around a changed field.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::hpa_star::HierarchicalGraph;
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![vec![0; 8]; 8];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(7), y: Some(7) },
+///         value: Some(2)
+///     }
+/// );
+///
+/// let mut hierarchical_graph = HierarchicalGraph::new(fs_example, aps_example, matrix_example.len(), 4).unwrap();
+///
+/// assert_eq!(hierarchical_graph.resolve(start_end_fields).unwrap().cost, 14);
+///
+/// for y in 0..7 {
+///     hierarchical_graph.update_field(Point { x: Some(4), y: Some(y) }, -1).unwrap();
+/// }
+///
+/// assert_eq!(hierarchical_graph.resolve(start_end_fields).unwrap().cost, 14);
+/// assert!(hierarchical_graph.resolve(start_end_fields).unwrap().points.contains(&Point { x: Some(4), y: Some(7) }));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct HierarchicalGraph {
+    fs: Vec<Field>,
+    aps: Vec<u32>,
+    matrix_size: usize,
+    cluster_size: usize,
+    clusters_per_line: usize,
+    borders: HashMap<(usize, usize), Vec<(usize, usize)>>,
+    intra_edges: Vec<HashMap<usize, Vec<(usize, usize)>>>
+}
+
+impl HierarchicalGraph {
+    pub fn new(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, cluster_size: usize) -> Result<Self, &'static str> {
+        if fs.is_empty() || aps.is_empty() || matrix_size == 0 || cluster_size == 0 {
+            return Err("The parameters MUST be initializes");
+        }
+
+        get_fields_number(&aps, matrix_size)?;
+
+        let clusters_per_line = matrix_size.div_ceil(cluster_size);
+        let mut hierarchical_graph = Self {
+            fs,
+            aps,
+            matrix_size,
+            cluster_size,
+            clusters_per_line,
+            borders: HashMap::new(),
+            intra_edges: vec![HashMap::new(); clusters_per_line * clusters_per_line]
+        };
+
+        for cluster in 0..clusters_per_line * clusters_per_line {
+            hierarchical_graph.build_borders(cluster)?;
+        }
+
+        for cluster in 0..clusters_per_line * clusters_per_line {
+            hierarchical_graph.build_intra_edges(cluster)?;
+        }
+
+        return Ok(hierarchical_graph);
+    }
+
+    /// Number of entrances of all the clusters.
+    pub fn abstract_nodes_number(&self) -> usize {
+        return self.intra_edges.iter().map(|cluster_edges| cluster_edges.len()).sum();
+    }
+
+    /// Change the value of a field, `-1` for a wall, and rebuild the cluster of the field and his neighbours.
+    pub fn update_field(&mut self, point: Point, value: i8) -> Result<(), &'static str> {
+        let index = point.get_index(self.matrix_size)?;
+
+        if index >= self.aps.len() - 1 {
+            return Err("The target point cannot be found inside the matrix");
+        }
+
+        let neighbour_indexes: Vec<usize> = get_element_childs_slice(&self.fs, &self.aps, index)?.iter()
+            .map(|child| child.coordinates.get_index(self.matrix_size))
+            .collect::<Result<Vec<usize>, &'static str>>()?;
+
+        for neighbour_index in neighbour_indexes {
+            let fs_range = match (self.aps.get(neighbour_index), self.aps.get(neighbour_index + 1)) {
+                (Some(fs_start_index), Some(fs_end_index)) => *fs_start_index as usize..*fs_end_index as usize,
+                _ => return Err("The APS vector does not match the FS vector")
+            };
+
+            for child in self.fs.get_mut(fs_range).ok_or("The APS vector does not match the FS vector")?.iter_mut() {
+                if child.coordinates == point {
+                    child.value = Some(value);
+                }
+            }
+        }
+
+        let cluster = self.get_cluster(index);
+        let mut changed_clusters = self.get_neighbour_clusters(cluster);
+
+        self.build_borders(cluster)?;
+
+        for neighbour_cluster in changed_clusters.iter() {
+            self.build_borders(*neighbour_cluster)?;
+        }
+
+        changed_clusters.push(cluster);
+
+        for changed_cluster in changed_clusters {
+            self.build_intra_edges(changed_cluster)?;
+        }
+
+        return Ok(());
+    }
+
+    /// Find a road between two points with the abstract graph.
+    pub fn resolve(&self, start_end_point: (Field, Field)) -> Result<Path, &'static str> {
+        return self.resolve_with_stats(start_end_point, &mut SearchStats::new());
+    }
+
+    /// Find a road between two points with the abstract graph and fill the statistics of this search only.
+    /// The statistics count the abstract nodes of the search on the abstract graph
+    /// and the fields of the BFS in the clusters of the start, of the end and of each refined abstract move.
+    /// The build of the abstract graph is not counted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+    /// use esgi_arena_resolver_algorithms::hpa_star::{ HierarchicalGraph, SearchStats };
+    ///
+    /// let matrix_example: Vec<Vec<i8>> = vec![vec![0; 8]; 8];
+    /// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+    /// let start_end_fields = (Field {
+    ///         coordinates: Point { x: Some(0), y: Some(0) },
+    ///         value: Some(1)
+    ///     },
+    ///     Field {
+    ///         coordinates: Point { x: Some(7), y: Some(7) },
+    ///         value: Some(2)
+    ///     }
+    /// );
+    /// let hierarchical_graph = HierarchicalGraph::new(fs_example, aps_example, matrix_example.len(), 4).unwrap();
+    /// let mut stats = SearchStats::new();
+    ///
+    /// hierarchical_graph.resolve_with_stats(start_end_fields, &mut stats).unwrap();
+    ///
+    /// assert_eq!(stats.path_cost, Some(14));
+    /// assert!(stats.nodes_expanded > 0);
+    /// ```
+    pub fn resolve_with_stats(&self, start_end_point: (Field, Field), stats: &mut SearchStats) -> Result<Path, &'static str> {
+        return record_road_search(stats, |stats| self.resolve_search(start_end_point, stats));
+    }
+
+    fn resolve_search(&self, start_end_point: (Field, Field), stats: &mut SearchStats) -> Result<Path, &'static str> {
+        let (start_point, end_point) = start_end_point;
+        let fields_number = self.aps.len() - 1;
+        let start_index = start_point.coordinates.get_index(self.matrix_size)?;
+        let end_index = end_point.coordinates.get_index(self.matrix_size)?;
+
+        if start_index >= fields_number || end_index >= fields_number {
+            return Err("The target point cannot be found inside the matrix");
+        }
+
+        if start_index == end_index {
+            return Ok(Path::new(vec![start_point.coordinates]));
+        }
+
+        let start_cluster = self.get_cluster(start_index);
+        let end_cluster = self.get_cluster(end_index);
+        let (start_distances, _) = self.cluster_bfs(start_cluster, start_index, None, stats)?;
+        let (end_distances, _) = self.cluster_bfs(end_cluster, end_index, None, stats)?;
+
+        let mut start_edges: Vec<(usize, usize)> = self.intra_edges[start_cluster].keys()
+            .filter_map(|node| start_distances[*node].map(|distance| (*node, distance)))
+            .collect();
+
+        if start_cluster == end_cluster {
+            if let Some(distance) = start_distances[end_index] {
+                start_edges.push((end_index, distance));
+            }
+        }
+
+        let heuristic = |index: usize| -> usize {
+            let (x, y) = (index / self.matrix_size, index % self.matrix_size);
+            let (end_x, end_y) = (end_index / self.matrix_size, end_index % self.matrix_size);
+
+            return x.abs_diff(end_x) + y.abs_diff(end_y);
+        };
+        let mut costs: HashMap<usize, usize> = HashMap::new();
+        let mut parents: HashMap<usize, usize> = HashMap::new();
+        let mut open_list: BinaryHeap<Reverse<(usize, usize)>> = BinaryHeap::new();
+
+        costs.insert(start_index, 0);
+        open_list.push(Reverse((heuristic(start_index), start_index)));
+        stats.update_open_list_size(open_list.len());
+
+        while let Some(Reverse((cost_with_heuristic, current_node))) = open_list.pop() {
+            let current_cost = costs[&current_node];
+
+            if cost_with_heuristic > current_cost + heuristic(current_node) {
+                continue;
+            }
+
+            stats.nodes_expanded += 1;
+
+            if current_node == end_index {
+                return self.refine_abstract_road(&parents, end_index, stats);
+            }
+
+            let mut abstract_edges = self.get_abstract_edges(current_node);
+
+            if current_node == start_index {
+                abstract_edges.extend(start_edges.iter().cloned());
+            }
+
+            if self.get_cluster(current_node) == end_cluster {
+                if let Some(distance) = end_distances[current_node] {
+                    abstract_edges.push((end_index, distance));
+                }
+            }
+
+            for (child_node, edge_cost) in abstract_edges {
+                let child_cost = current_cost + edge_cost;
+
+                if costs.get(&child_node).is_some_and(|cost| *cost <= child_cost) {
+                    continue;
+                }
+
+                if costs.insert(child_node, child_cost).is_some() {
+                    stats.reopened_nodes += 1;
+                }
+
+                parents.insert(child_node, current_node);
+                open_list.push(Reverse((child_cost + heuristic(child_node), child_node)));
+                stats.nodes_generated += 1;
+            }
+
+            stats.update_open_list_size(open_list.len());
+        }
+
+        return Err("It seem that it has no end to this level");
+    }
+
+    fn get_cluster(&self, index: usize) -> usize {
+        let (x, y) = (index / self.matrix_size, index % self.matrix_size);
+
+        return (x / self.cluster_size) * self.clusters_per_line + y / self.cluster_size;
+    }
+
+    fn get_neighbour_clusters(&self, cluster: usize) -> Vec<usize> {
+        let (cluster_x, cluster_y) = (cluster / self.clusters_per_line, cluster % self.clusters_per_line);
+        let mut neighbour_clusters: Vec<usize> = Vec::new();
+
+        if cluster_x > 0 {
+            neighbour_clusters.push(cluster - self.clusters_per_line);
+        }
+
+        if cluster_y > 0 {
+            neighbour_clusters.push(cluster - 1);
+        }
+
+        if cluster_y + 1 < self.clusters_per_line {
+            neighbour_clusters.push(cluster + 1);
+        }
+
+        if cluster_x + 1 < self.clusters_per_line {
+            neighbour_clusters.push(cluster + self.clusters_per_line);
+        }
+
+        return neighbour_clusters;
+    }
+
+    /// Check that a bot can go from a field to his neighbour and come back.
+    fn is_open_move(&self, from_index: usize, to_index: usize) -> Result<bool, &'static str> {
+        let is_open_child = |parent_index: usize, child_index: usize| -> Result<bool, &'static str> {
+            for child in get_element_childs_slice(&self.fs, &self.aps, parent_index)? {
+                if child.coordinates.get_index(self.matrix_size)? == child_index {
+                    return Ok(child.value != Some(-1));
+                }
+            }
+
+            return Ok(false);
+        };
+
+        return Ok(is_open_child(from_index, to_index)? && is_open_child(to_index, from_index)?);
+    }
+
+    /// Build the entrances of the borders on the right and under a cluster.
+    fn build_borders(&mut self, cluster: usize) -> Result<(), &'static str> {
+        let (cluster_x, cluster_y) = (cluster / self.clusters_per_line, cluster % self.clusters_per_line);
+        let lines = cluster_x * self.cluster_size..((cluster_x + 1) * self.cluster_size).min(self.matrix_size);
+        let columns = cluster_y * self.cluster_size..((cluster_y + 1) * self.cluster_size).min(self.matrix_size);
+
+        if cluster_y + 1 < self.clusters_per_line {
+            let y = columns.end;
+            let border_fields: Vec<(usize, usize)> = lines.clone().map(|x| (x * self.matrix_size + y - 1, x * self.matrix_size + y)).collect();
+            let entrances = self.get_border_entrances(&border_fields)?;
+
+            self.borders.insert((cluster, cluster + 1), entrances);
+        }
+
+        if cluster_x + 1 < self.clusters_per_line {
+            let x = lines.end;
+            let border_fields: Vec<(usize, usize)> = columns.map(|y| ((x - 1) * self.matrix_size + y, x * self.matrix_size + y)).collect();
+            let entrances = self.get_border_entrances(&border_fields)?;
+
+            self.borders.insert((cluster, cluster + self.clusters_per_line), entrances);
+        }
+
+        return Ok(());
+    }
+
+    /// Get the entrances of a border from the pairs of fields on each side of the border.
+    fn get_border_entrances(&self, border_fields: &[(usize, usize)]) -> Result<Vec<(usize, usize)>, &'static str> {
+        let mut entrances: Vec<(usize, usize)> = Vec::new();
+        let mut open_segment: Vec<(usize, usize)> = Vec::new();
+
+        for (position, (from_index, to_index)) in border_fields.iter().enumerate() {
+            let is_open = self.is_open_move(*from_index, *to_index)?;
+
+            if is_open {
+                open_segment.push((*from_index, *to_index));
+            }
+
+            if (! is_open || position + 1 == border_fields.len()) && ! open_segment.is_empty() {
+                if open_segment.len() < MAX_SINGLE_ENTRANCE_LENGTH {
+                    entrances.push(open_segment[open_segment.len() / 2]);
+                } else {
+                    entrances.push(open_segment[0]);
+                    entrances.push(open_segment[open_segment.len() - 1]);
+                }
+
+                open_segment.clear();
+            }
+        }
+
+        return Ok(entrances);
+    }
+
+    /// Get the entrances of a cluster with the fields on the other side of each entrance.
+    fn get_cluster_entrances(&self, cluster: usize) -> Vec<(usize, usize)> {
+        let mut cluster_entrances: Vec<(usize, usize)> = Vec::new();
+
+        for neighbour_cluster in self.get_neighbour_clusters(cluster) {
+            let border = (cluster.min(neighbour_cluster), cluster.max(neighbour_cluster));
+
+            for (from_index, to_index) in self.borders.get(&border).into_iter().flatten() {
+                if self.get_cluster(*from_index) == cluster {
+                    cluster_entrances.push((*from_index, *to_index));
+                } else {
+                    cluster_entrances.push((*to_index, *from_index));
+                }
+            }
+        }
+
+        return cluster_entrances;
+    }
+
+    /// Compute the distances between the entrances of a cluster without leaving the cluster.
+    fn build_intra_edges(&mut self, cluster: usize) -> Result<(), &'static str> {
+        let mut cluster_nodes: Vec<usize> = self.get_cluster_entrances(cluster).into_iter().map(|(index, _)| index).collect();
+        let mut cluster_edges: HashMap<usize, Vec<(usize, usize)>> = HashMap::new();
+
+        cluster_nodes.sort_unstable();
+        cluster_nodes.dedup();
+
+        for node in cluster_nodes.iter() {
+            let (distances, _) = self.cluster_bfs(cluster, *node, None, &mut SearchStats::new())?;
+            let node_edges: Vec<(usize, usize)> = cluster_nodes.iter()
+                .filter(|other_node| *other_node != node)
+                .filter_map(|other_node| distances[*other_node].map(|distance| (*other_node, distance)))
+                .collect();
+
+            cluster_edges.insert(*node, node_edges);
+        }
+
+        self.intra_edges[cluster] = cluster_edges;
+
+        return Ok(());
+    }
+
+    /// Get the abstract nodes next to a node: the entrances of the same cluster and the field on the other side of the border.
+    fn get_abstract_edges(&self, node: usize) -> Vec<(usize, usize)> {
+        let cluster = self.get_cluster(node);
+        let mut abstract_edges: Vec<(usize, usize)> = self.intra_edges[cluster].get(&node).cloned().unwrap_or_default();
+
+        for (from_index, to_index) in self.get_cluster_entrances(cluster) {
+            if from_index == node {
+                abstract_edges.push((to_index, 1));
+            }
+        }
+
+        return abstract_edges;
+    }
+
+    fn cluster_bfs(&self, cluster: usize, start_index: usize, end_index: Option<usize>, stats: &mut SearchStats) -> Result<BfsTree, &'static str> {
+        return bfs_search_with_stats(&self.fs, &self.aps, self.matrix_size, start_index, end_index, &|_, child_index| self.get_cluster(child_index) == cluster, stats);
+    }
+
+    /// Build the road of fields from the abstract nodes found by the search.
+    fn refine_abstract_road(&self, abstract_parents: &HashMap<usize, usize>, end_index: usize, stats: &mut SearchStats) -> Result<Path, &'static str> {
+        let mut abstract_road: Vec<usize> = vec![end_index];
+
+        while let Some(parent_node) = abstract_parents.get(abstract_road.last().unwrap()) {
+            abstract_road.push(*parent_node);
+        }
+
+        abstract_road.reverse();
+
+        let mut path = Path::new(vec![Point::from_index(abstract_road[0], self.matrix_size)?]);
+
+        for nodes in abstract_road.windows(2) {
+            let cluster = self.get_cluster(nodes[0]);
+
+            if cluster != self.get_cluster(nodes[1]) {
+                path = path.concat(Path::new(vec![Point::from_index(nodes[0], self.matrix_size)?, Point::from_index(nodes[1], self.matrix_size)?]))?;
+            } else {
+                let (_, parents) = self.cluster_bfs(cluster, nodes[0], Some(nodes[1]), stats)?;
+
+                path = path.concat(Path::new(get_index_road_from_parents(&parents, self.matrix_size, nodes[1])?))?;
+            }
+        }
+
+        return Ok(path);
+    }
+}
+
+/// HPA* resolver function.
+/// It's build the abstract graph of the bord and find a road between two points with it.
+/// For many searches on the same bord, build one `HierarchicalGraph` and call his `resolve` method.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::hpa_star::hpa_star_resolver;
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![1, 0, 0, 0],
+///     vec![-1, -1, -1, 0],
+///     vec![0, 0, 0, 0],
+///     vec![2, -1, -1, -1]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(3), y: Some(0) },
+///         value: Some(2)
+///     }
+/// );
+///
+/// let path = hpa_star_resolver(fs_example, aps_example, matrix_example.len(), start_end_fields, 2).unwrap();
+///
+/// assert_eq!(path.cost, 9);
+/// assert!(path.is_valid_on(&matrix_example));
+/// ```
+pub fn hpa_star_resolver(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), cluster_size: usize) -> Result<Path, &'static str> {
+    return hpa_star_resolver_with_stats(fs, aps, matrix_size, start_end_point, cluster_size, &mut SearchStats::new());
+}
+
+/// HPA* resolver function which fill the search statistics.
+/// The statistics are the same as the `HierarchicalGraph::resolve_with_stats` ones, but the elapsed time includes the build of the abstract graph.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::hpa_star::{ hpa_star_resolver_with_stats, SearchStats };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![1, 0, 0, 0],
+///     vec![-1, -1, -1, 0],
+///     vec![0, 0, 0, 0],
+///     vec![2, -1, -1, -1]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(3), y: Some(0) },
+///         value: Some(2)
+///     }
+/// );
+/// let mut stats = SearchStats::new();
+///
+/// hpa_star_resolver_with_stats(fs_example, aps_example, matrix_example.len(), start_end_fields, 2, &mut stats).unwrap();
+///
+/// assert_eq!(stats.path_cost, Some(9));
+/// assert!(stats.nodes_generated > 0);
+/// ```
+pub fn hpa_star_resolver_with_stats(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), cluster_size: usize, stats: &mut SearchStats) -> Result<Path, &'static str> {
+    return record_road_search(stats, |stats| {
+        if start_end_point == (Field::new(), Field::new()) {
+            return Err("The parameters MUST be initializes");
+        }
+
+        return HierarchicalGraph::new(fs, aps, matrix_size, cluster_size)?.resolve_search(start_end_point, stats);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::fs_aps_from_matrix;
+
+    #[test]
+    fn get_border_entrances_test() {
+        let mut matrix = vec![vec![0; 8]; 8];
+
+        matrix[3][1] = -1;
+
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let hierarchical_graph = HierarchicalGraph::new(fs, aps, 8, 8).unwrap();
+        let border_fields: Vec<(usize, usize)> = (0..8).map(|y| (3 * 8 + y, 4 * 8 + y)).collect();
+
+        assert_eq!(hierarchical_graph.abstract_nodes_number(), 0);
+        assert_eq!(hierarchical_graph.get_border_entrances(&border_fields), Ok(vec![(3 * 8, 4 * 8), (3 * 8 + 2, 4 * 8 + 2), (3 * 8 + 7, 4 * 8 + 7)]));
+    }
+
+    #[test]
+    fn update_field_test() {
+        let matrix = vec![vec![0; 6]; 6];
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let mut hierarchical_graph = HierarchicalGraph::new(fs.clone(), aps.clone(), 6, 3).unwrap();
+        let abstract_nodes_number = hierarchical_graph.abstract_nodes_number();
+
+        hierarchical_graph.update_field(Point { x: Some(1), y: Some(2) }, -1).unwrap();
+
+        assert_ne!(hierarchical_graph.abstract_nodes_number(), abstract_nodes_number);
+
+        hierarchical_graph.update_field(Point { x: Some(1), y: Some(2) }, 0).unwrap();
+
+        assert_eq!(hierarchical_graph, HierarchicalGraph::new(fs, aps, 6, 3).unwrap());
+    }
+}
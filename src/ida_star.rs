@@ -0,0 +1,373 @@
+//! # Description
+//! This is the IDA* algorithm module.
+//! The IDA* run many depth first searches with a growing limit on the cost of the fields.
+//! Only the current road is kept in memory, so the memory used is linear in the road length.
+//! [For more explainations](https://en.wikipedia.org/wiki/Iterative_deepening_A*)
+
+pub use crate::graph::{Field, Point};
+pub use crate::a_star::get_manhattan_distance_heuristic;
+pub use crate::path::Path;
+pub use crate::stats::SearchStats;
+use crate::graph::get_element_childs_slice;
+use crate::stats::record_road_search;
+
+/// Result of one depth first search with a cost limit.
+enum IdaStarStep {
+    Found,
+    NextLimit(Option<u32>)
+}
+
+/// Generic IDA* search on any state space.
+/// The states are only discovered with the `neighbours` closure, which give the next states and the cost to move on them.
+/// It's return the road from the start to the first goal found and his cost.
+/// A road whose cost cannot be represented by a `u32` is not searched.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::ida_star::ida_star_search;
+///
+/// let (road, cost) = ida_star_search(
+///     0_i32,
+///     |state: &i32| vec![(state + 1, 1), (state * 2, 1)],
+///     |state: &i32| if *state < 10 { 1 } else { 0 },
+///     |state: &i32| *state == 10
+/// ).unwrap();
+///
+/// assert_eq!(road, vec![0, 1, 2, 4, 5, 10]);
+/// assert_eq!(cost, 5);
+/// ```
+pub fn ida_star_search<S, N, H, G>(start: S, neighbours: N, heuristic: H, is_goal: G) -> Option<(Vec<S>, u32)>
+where
+    S: Clone + PartialEq,
+    N: Fn(&S) -> Vec<(S, u32)>,
+    H: Fn(&S) -> u32,
+    G: Fn(&S) -> bool
+{
+    return ida_star_search_with_stats(start, neighbours, heuristic, is_goal, &mut SearchStats::new());
+}
+
+/// Generic IDA* search which add his work to the search statistics.
+/// The current road is the open list, and each new cost limit generate again all the states of the previous one,
+/// so they are counted as reopened.
+fn ida_star_search_with_stats<S, N, H, G>(start: S, neighbours: N, heuristic: H, is_goal: G, stats: &mut SearchStats) -> Option<(Vec<S>, u32)>
+where
+    S: Clone + PartialEq,
+    N: Fn(&S) -> Vec<(S, u32)>,
+    H: Fn(&S) -> u32,
+    G: Fn(&S) -> bool
+{
+    let mut cost_limit = heuristic(&start);
+    let mut road: Vec<S> = vec![start];
+
+    stats.update_open_list_size(road.len());
+
+    loop {
+        let mut road_cost: u32 = 0;
+        let generated_before_limit = stats.nodes_generated;
+
+        match ida_star_depth_search(&mut road, 0, cost_limit, &mut road_cost, &neighbours, &heuristic, &is_goal, stats) {
+            IdaStarStep::Found => return Some((road, road_cost)),
+            IdaStarStep::NextLimit(Some(next_cost_limit)) => {
+                stats.reopened_nodes += stats.nodes_generated - generated_before_limit;
+                cost_limit = next_cost_limit;
+            },
+            IdaStarStep::NextLimit(None) => return None
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn ida_star_depth_search<S, N, H, G>(road: &mut Vec<S>, weight: u32, cost_limit: u32, road_cost: &mut u32, neighbours: &N, heuristic: &H, is_goal: &G, stats: &mut SearchStats) -> IdaStarStep
+where
+    S: Clone + PartialEq,
+    N: Fn(&S) -> Vec<(S, u32)>,
+    H: Fn(&S) -> u32,
+    G: Fn(&S) -> bool
+{
+    let current_state = road.last().unwrap().clone();
+    let current_cost = match weight.checked_add(heuristic(&current_state)) {
+        Some(current_cost) => current_cost,
+        None => return IdaStarStep::NextLimit(None)
+    };
+
+    if current_cost > cost_limit {
+        return IdaStarStep::NextLimit(Some(current_cost));
+    }
+
+    if is_goal(&current_state) {
+        *road_cost = weight;
+        return IdaStarStep::Found;
+    }
+
+    let mut next_cost_limit: Option<u32> = None;
+
+    stats.nodes_expanded += 1;
+
+    for (child, move_cost) in neighbours(&current_state) {
+        let child_weight = match weight.checked_add(move_cost) {
+            Some(child_weight) => child_weight,
+            None => continue
+        };
+
+        if road.contains(&child) {
+            continue;
+        }
+
+        road.push(child);
+        stats.nodes_generated += 1;
+        stats.update_open_list_size(road.len());
+
+        match ida_star_depth_search(road, child_weight, cost_limit, road_cost, neighbours, heuristic, is_goal, stats) {
+            IdaStarStep::Found => return IdaStarStep::Found,
+            IdaStarStep::NextLimit(Some(child_cost_limit)) => {
+                next_cost_limit = Some(next_cost_limit.map_or(child_cost_limit, |cost| cost.min(child_cost_limit)));
+            },
+            IdaStarStep::NextLimit(None) => {}
+        }
+
+        road.pop();
+    }
+
+    return IdaStarStep::NextLimit(next_cost_limit);
+}
+
+/// IDA* resolver function.
+/// It's find the shorter path between two points of a graph with the manhattan distance as heuristic.
+/// The input and the output are the same as the `a_star_resolver` function.
+/// 
+/// A depth first search need to try all the roads from the start before knowing that a level has no end,
+/// so the end is checked to be reachable before the search with a flag by field, which is smaller than the bord itself.
+/// The search keeps nothing more than the current road in memory.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::ida_star::ida_star_resolver;
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![2, 0, 0],
+///     vec![-1, -1, 0],
+///     vec![1, 0, 0]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(2), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(2)
+///     }
+/// );
+///
+/// assert_eq!(ida_star_resolver(fs_example, aps_example, matrix_example.len(), start_end_fields).unwrap().points, vec![
+///     Point { x: Some(2), y: Some(0) },
+///     Point { x: Some(2), y: Some(1) },
+///     Point { x: Some(2), y: Some(2) },
+///     Point { x: Some(1), y: Some(2) },
+///     Point { x: Some(0), y: Some(2) },
+///     Point { x: Some(0), y: Some(1) },
+///     Point { x: Some(0), y: Some(0) }
+/// ]);
+/// ```
+pub fn ida_star_resolver(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field)) -> Result<Path, &'static str> {
+    return ida_star_resolver_with_stats(fs, aps, matrix_size, start_end_point, &mut SearchStats::new());
+}
+
+/// IDA* resolver function which fill the search statistics.
+/// The open list size is the length of the current road, and the fields generated again by a new cost limit are the reopened fields.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::ida_star::{ ida_star_resolver_with_stats, SearchStats };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![2, 0, 0],
+///     vec![-1, -1, 0],
+///     vec![1, 0, 0]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(2), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(2)
+///     }
+/// );
+/// let mut stats = SearchStats::new();
+///
+/// ida_star_resolver_with_stats(fs_example, aps_example, matrix_example.len(), start_end_fields, &mut stats).unwrap();
+///
+/// assert_eq!(stats.path_cost, Some(6));
+/// assert_eq!(stats.max_open_list_size, 7);
+/// assert!(stats.reopened_nodes > 0);
+/// ```
+pub fn ida_star_resolver_with_stats(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), stats: &mut SearchStats) -> Result<Path, &'static str> {
+    return record_road_search(stats, |stats| ida_star_road_search(fs, aps, matrix_size, start_end_point, stats));
+}
+
+fn ida_star_road_search(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), stats: &mut SearchStats) -> Result<Path, &'static str> {
+    if fs.is_empty() || aps.is_empty() || start_end_point == (Field::new(), Field::new()) {
+        return Err("The parameters MUST be initializes");
+    }
+
+    let (start_point, end_point) = start_end_point;
+    let fields_number = aps.len() - 1;
+    let start_index = start_point.coordinates.get_index(matrix_size)?;
+    let end_index = end_point.coordinates.get_index(matrix_size)?;
+
+    if start_index >= fields_number || end_index >= fields_number {
+        return Err("The target point cannot be found inside the matrix");
+    }
+
+    if ! is_end_reachable(&fs, &aps, start_index, end_index, matrix_size)? {
+        return Err("It seem that it has no end to this level");
+    }
+
+    let neighbours = |field: &Field| -> Vec<(Field, u32)> {
+        let field_index = match field.coordinates.get_index(matrix_size) {
+            Ok(field_index) => field_index,
+            Err(_) => return Vec::new()
+        };
+
+        return get_element_childs_slice(&fs, &aps, field_index)
+            .unwrap_or(&[])
+            .iter()
+            .filter(|child| child.value != Some(-1))
+            .map(|child| (*child, 1))
+            .collect();
+    };
+    let heuristic = |field: &Field| get_manhattan_distance_heuristic(field.coordinates, end_point.coordinates) as u32;
+    let is_goal = |field: &Field| field.coordinates == end_point.coordinates;
+
+    let (road, road_cost) = ida_star_search_with_stats(start_point, neighbours, heuristic, is_goal, stats).ok_or("It seem that it has no end to this level")?;
+
+    return Ok(Path::with_cost(road.iter().map(|field| field.coordinates).collect(), road_cost as usize));
+}
+
+/// Check that the end can be reached from the start without going on a wall.
+/// Only a visited flag is kept for each field, the distances and the parents are not needed.
+fn is_end_reachable(fs: &[Field], aps: &[u32], start_index: usize, end_index: usize, matrix_size: usize) -> Result<bool, &'static str> {
+    let mut visited: Vec<bool> = vec![false; aps.len() - 1];
+    let mut stack: Vec<usize> = vec![start_index];
+
+    visited[start_index] = true;
+
+    while let Some(field_index) = stack.pop() {
+        if field_index == end_index {
+            return Ok(true);
+        }
+
+        for child in get_element_childs_slice(fs, aps, field_index)? {
+            if child.value == Some(-1) {
+                continue;
+            }
+
+            let child_index = child.coordinates.get_index(matrix_size)?;
+
+            if child_index < visited.len() && ! visited[child_index] {
+                visited[child_index] = true;
+                stack.push(child_index);
+            }
+        }
+    }
+
+    return Ok(false);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ida_star_search_no_goal_test() {
+        let result = ida_star_search(
+            0_u32,
+            |state: &u32| if *state < 5 { vec![(state + 1, 2)] } else { Vec::new() },
+            |_: &u32| 0,
+            |state: &u32| *state == 6
+        );
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn ida_star_search_cheaper_road_test() {
+        let (road, cost) = ida_star_search(
+            'a',
+            |state: &char| match state {
+                'a' => vec![('b', 1), ('c', 4)],
+                'b' => vec![('d', 5)],
+                'c' => vec![('d', 1)],
+                _ => Vec::new()
+            },
+            |_: &char| 0,
+            |state: &char| *state == 'd'
+        ).unwrap();
+
+        assert_eq!(road, vec!['a', 'c', 'd']);
+        assert_eq!(cost, 5);
+    }
+
+    #[test]
+    fn ida_star_resolver_closed_end_test() {
+        let matrix = vec![
+            vec![1, 0, 0, 0],
+            vec![0, 0, 0, 0],
+            vec![0, 0, -1, -1],
+            vec![0, 0, -1, 2]
+        ];
+        let (fs, aps) = crate::graph::fs_aps_from_matrix(matrix).unwrap();
+        let start_end = (
+            Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(1) },
+            Field { coordinates: Point { x: Some(3), y: Some(3) }, value: Some(2) }
+        );
+
+        assert_eq!(ida_star_resolver(fs, aps, 4, start_end), Err("It seem that it has no end to this level"));
+    }
+
+    #[test]
+    fn ida_star_resolver_open_closed_end_test() {
+        let mut matrix: Vec<Vec<i8>> = vec![vec![0; 6]; 6];
+
+        matrix[0][0] = 1;
+        matrix[5][5] = 2;
+        matrix[4][5] = -1;
+        matrix[5][4] = -1;
+
+        let (fs, aps) = crate::graph::fs_aps_from_matrix(matrix).unwrap();
+        let start_end = (
+            Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(1) },
+            Field { coordinates: Point { x: Some(5), y: Some(5) }, value: Some(2) }
+        );
+
+        assert_eq!(ida_star_resolver(fs, aps, 6, start_end), Err("It seem that it has no end to this level"));
+    }
+
+    #[test]
+    fn ida_star_search_cost_overflow_test() {
+        let result = ida_star_search(
+            0_u32,
+            |state: &u32| if *state < 3 { vec![(state + 1, u32::MAX / 2)] } else { Vec::new() },
+            |_: &u32| 0,
+            |state: &u32| *state == 3
+        );
+
+        assert_eq!(result, None);
+
+        let result = ida_star_search(
+            0_u32,
+            |state: &u32| if *state == 0 { vec![(1, u32::MAX), (2, 1)] } else { Vec::new() },
+            |state: &u32| if *state == 1 { 1 } else { 0 },
+            |state: &u32| *state == 2
+        );
+
+        assert_eq!(result, Some((vec![0, 2], 1)));
+    }
+}
@@ -0,0 +1,477 @@
+//! # Description
+//! This is the Jump Point Search module.
+//! On a bord where all the moves have the same cost, many roads are symmetric.
+//! The JPS skip these roads by jumping in straight lines and only stop on the fields
+//! which can change the direction of the shorter road (the jump points).
+//! [For more explainations](https://en.wikipedia.org/wiki/Jump_point_search)
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+pub use crate::graph::{Field, Point};
+pub use crate::path::Path;
+pub use crate::stats::SearchStats;
+use crate::graph::get_fields_number;
+use crate::stats::record_road_search;
+
+const STRAIGHT_MOVE_COST: u32 = 10;
+const DIAGONAL_MOVE_COST: u32 = 14;
+
+/// The moves allowed from a field to his neighbours.
+/// `Four` only allow the horizontal and vertical moves like the FS APS graph.
+/// `Eight` also allow the diagonal moves when the two fields beside the diagonal are not walls.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Connectivity {
+    Four,
+    Eight
+}
+
+/// The bord of a JPS search.
+/// It's rebuild from the FS vector because the JPS need to look at the fields around a field.
+struct JpsGrid {
+    matrix_size: usize,
+    walkable: Vec<bool>,
+    end: (i64, i64),
+    connectivity: Connectivity
+}
+
+impl JpsGrid {
+    fn is_walkable(&self, x: i64, y: i64) -> bool {
+        if x < 0 || y < 0 || x >= self.matrix_size as i64 || y >= self.matrix_size as i64 {
+            return false;
+        }
+
+        return self.walkable[x as usize * self.matrix_size + y as usize];
+    }
+
+    fn index(&self, x: i64, y: i64) -> usize {
+        return x as usize * self.matrix_size + y as usize;
+    }
+
+    fn coordinates(&self, index: usize) -> (i64, i64) {
+        return ((index / self.matrix_size) as i64, (index % self.matrix_size) as i64);
+    }
+
+    fn heuristic(&self, x: i64, y: i64) -> u32 {
+        return move_cost((x, y), self.end, self.connectivity);
+    }
+
+    fn neighbours(&self, x: i64, y: i64, parent: Option<(i64, i64)>) -> Vec<(i64, i64)> {
+        let mut neighbours: Vec<(i64, i64)> = Vec::new();
+
+        let (dx, dy) = match parent {
+            Some((parent_x, parent_y)) => ((x - parent_x).signum(), (y - parent_y).signum()),
+            None => {
+                for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                    if self.is_walkable(x + dx, y + dy) {
+                        neighbours.push((x + dx, y + dy));
+                    }
+                }
+
+                if self.connectivity == Connectivity::Eight {
+                    for (dx, dy) in [(-1, -1), (-1, 1), (1, -1), (1, 1)] {
+                        if self.is_walkable(x + dx, y) && self.is_walkable(x, y + dy) && self.is_walkable(x + dx, y + dy) {
+                            neighbours.push((x + dx, y + dy));
+                        }
+                    }
+                }
+
+                return neighbours;
+            }
+        };
+
+        let mut candidates: Vec<(i64, i64)> = Vec::new();
+
+        match self.connectivity {
+            Connectivity::Four => {
+                if dx != 0 {
+                    candidates.extend([(x, y - 1), (x, y + 1), (x + dx, y)]);
+                } else {
+                    candidates.extend([(x - 1, y), (x + 1, y), (x, y + dy)]);
+                }
+            },
+            Connectivity::Eight => {
+                if dx != 0 && dy != 0 {
+                    candidates.extend([(x, y + dy), (x + dx, y)]);
+
+                    if self.is_walkable(x, y + dy) && self.is_walkable(x + dx, y) {
+                        candidates.push((x + dx, y + dy));
+                    }
+                } else if dx != 0 {
+                    let is_next_walkable = self.is_walkable(x + dx, y);
+                    let is_top_walkable = self.is_walkable(x, y + 1);
+                    let is_bottom_walkable = self.is_walkable(x, y - 1);
+
+                    if is_next_walkable {
+                        candidates.push((x + dx, y));
+
+                        if is_top_walkable {
+                            candidates.push((x + dx, y + 1));
+                        }
+
+                        if is_bottom_walkable {
+                            candidates.push((x + dx, y - 1));
+                        }
+                    }
+
+                    candidates.extend([(x, y + 1), (x, y - 1)]);
+                } else {
+                    let is_next_walkable = self.is_walkable(x, y + dy);
+                    let is_right_walkable = self.is_walkable(x + 1, y);
+                    let is_left_walkable = self.is_walkable(x - 1, y);
+
+                    if is_next_walkable {
+                        candidates.push((x, y + dy));
+
+                        if is_right_walkable {
+                            candidates.push((x + 1, y + dy));
+                        }
+
+                        if is_left_walkable {
+                            candidates.push((x - 1, y + dy));
+                        }
+                    }
+
+                    candidates.extend([(x + 1, y), (x - 1, y)]);
+                }
+            }
+        }
+
+        for (candidate_x, candidate_y) in candidates {
+            if self.is_walkable(candidate_x, candidate_y) {
+                neighbours.push((candidate_x, candidate_y));
+            }
+        }
+
+        return neighbours;
+    }
+
+    fn jump(&self, x: i64, y: i64, dx: i64, dy: i64) -> Option<(i64, i64)> {
+        if ! self.is_walkable(x, y) {
+            return None;
+        }
+
+        if (x, y) == self.end {
+            return Some((x, y));
+        }
+
+        if dx != 0 && dy != 0 {
+            if self.jump(x + dx, y, dx, 0).is_some() || self.jump(x, y + dy, 0, dy).is_some() {
+                return Some((x, y));
+            }
+        } else if dx != 0 {
+            if (self.is_walkable(x, y - 1) && ! self.is_walkable(x - dx, y - 1))
+                || (self.is_walkable(x, y + 1) && ! self.is_walkable(x - dx, y + 1)) {
+                return Some((x, y));
+            }
+        } else {
+            if (self.is_walkable(x - 1, y) && ! self.is_walkable(x - 1, y - dy))
+                || (self.is_walkable(x + 1, y) && ! self.is_walkable(x + 1, y - dy)) {
+                return Some((x, y));
+            }
+
+            if self.connectivity == Connectivity::Four
+                && (self.jump(x + 1, y, 1, 0).is_some() || self.jump(x - 1, y, -1, 0).is_some()) {
+                return Some((x, y));
+            }
+        }
+
+        if self.is_walkable(x + dx, y) && self.is_walkable(x, y + dy) {
+            return self.jump(x + dx, y + dy, dx, dy);
+        }
+
+        return None;
+    }
+}
+
+/// Get the cost of a straight or diagonal move between two fields.
+fn move_cost(from: (i64, i64), to: (i64, i64), connectivity: Connectivity) -> u32 {
+    let x_distance = (from.0 - to.0).unsigned_abs() as u32;
+    let y_distance = (from.1 - to.1).unsigned_abs() as u32;
+
+    return match connectivity {
+        Connectivity::Four => STRAIGHT_MOVE_COST * (x_distance + y_distance),
+        Connectivity::Eight => DIAGONAL_MOVE_COST * x_distance.min(y_distance) + STRAIGHT_MOVE_COST * (x_distance.max(y_distance) - x_distance.min(y_distance))
+    };
+}
+
+/// JPS resolver function.
+/// It's find the shorter path between two points of a graph with only horizontal and vertical moves.
+/// The input and the output are the same as the `a_star_resolver` function.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::jps::jps_resolver;
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![2, 0, 0],
+///     vec![-1, -1, 0],
+///     vec![1, 0, 0]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(2), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(2)
+///     }
+/// );
+///
+/// assert_eq!(jps_resolver(fs_example, aps_example, matrix_example.len(), start_end_fields).unwrap().points, vec![
+///     Point { x: Some(2), y: Some(0) },
+///     Point { x: Some(2), y: Some(1) },
+///     Point { x: Some(2), y: Some(2) },
+///     Point { x: Some(1), y: Some(2) },
+///     Point { x: Some(0), y: Some(2) },
+///     Point { x: Some(0), y: Some(1) },
+///     Point { x: Some(0), y: Some(0) }
+/// ]);
+/// ```
+pub fn jps_resolver(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field)) -> Result<Path, &'static str> {
+    return jps_resolver_with_connectivity(fs, aps, matrix_size, start_end_point, Connectivity::Four);
+}
+
+/// JPS resolver function with a choice between the horizontal and vertical moves only
+/// or the diagonal moves too.
+/// The returned road contains all the fields between the jump points.
+/// With `Four` each move of the road cost 1, like the other resolvers.
+/// With `Eight` the cost of the road is the octile cost: 10 for a horizontal or vertical move and 14 for a diagonal move.
+/// The diagonal moves are not handled by the `moves` and `is_valid_on` functions of the `Path`.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::jps::{ jps_resolver_with_connectivity, Connectivity };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![1, 0, 0],
+///     vec![0, 0, 0],
+///     vec![0, 0, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(2), y: Some(2) },
+///         value: Some(2)
+///     }
+/// );
+///
+/// let road = jps_resolver_with_connectivity(fs_example, aps_example, matrix_example.len(), start_end_fields, Connectivity::Eight).unwrap();
+///
+/// assert_eq!(road.points, vec![
+///     Point { x: Some(0), y: Some(0) },
+///     Point { x: Some(1), y: Some(1) },
+///     Point { x: Some(2), y: Some(2) }
+/// ]);
+/// assert_eq!(road.cost, 28);
+/// ```
+pub fn jps_resolver_with_connectivity(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), connectivity: Connectivity) -> Result<Path, &'static str> {
+    return jps_resolver_with_stats(fs, aps, matrix_size, start_end_point, connectivity, &mut SearchStats::new());
+}
+
+/// JPS resolver function which fill the search statistics.
+/// Only the jump points are added to the open list, so there is less generated fields than with the A*.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::jps::{ jps_resolver_with_stats, Connectivity, SearchStats };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![2, 0, 0],
+///     vec![-1, -1, 0],
+///     vec![1, 0, 0]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(2), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(2)
+///     }
+/// );
+/// let mut stats = SearchStats::new();
+///
+/// jps_resolver_with_stats(fs_example, aps_example, matrix_example.len(), start_end_fields, Connectivity::Four, &mut stats).unwrap();
+///
+/// assert_eq!(stats.path_cost, Some(6));
+/// assert!(stats.nodes_expanded > 0);
+/// ```
+pub fn jps_resolver_with_stats(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), connectivity: Connectivity, stats: &mut SearchStats) -> Result<Path, &'static str> {
+    return record_road_search(stats, |stats| jps_search(fs, aps, matrix_size, start_end_point, connectivity, stats));
+}
+
+fn jps_search(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), connectivity: Connectivity, stats: &mut SearchStats) -> Result<Path, &'static str> {
+    if fs.is_empty() || aps.is_empty() || matrix_size == 0 || start_end_point == (Field::new(), Field::new()) {
+        return Err("The parameters MUST be initializes");
+    }
+
+    let (start_point, end_point) = start_end_point;
+    let fields_number = get_fields_number(&aps, matrix_size)?;
+    let start_index = start_point.coordinates.get_index(matrix_size)?;
+    let end_index = end_point.coordinates.get_index(matrix_size)?;
+
+    if start_index >= fields_number || end_index >= fields_number {
+        return Err("The target point cannot be found inside the matrix");
+    }
+
+    let mut walkable: Vec<bool> = vec![false; fields_number];
+
+    for field in fs.iter() {
+        let field_index = field.coordinates.get_index(matrix_size)?;
+
+        if field_index < fields_number {
+            walkable[field_index] = field.value.is_some() && field.value != Some(-1);
+        }
+    }
+
+    walkable[start_index] = true;
+
+    let mut grid = JpsGrid {
+        matrix_size,
+        walkable,
+        end: (0, 0),
+        connectivity
+    };
+    grid.end = grid.coordinates(end_index);
+
+    let mut weights: Vec<Option<u32>> = vec![None; fields_number];
+    let mut parents: Vec<Option<usize>> = vec![None; fields_number];
+    let mut closed: Vec<bool> = vec![false; fields_number];
+    let mut open_list: BinaryHeap<Reverse<(u32, usize)>> = BinaryHeap::new();
+    let (start_x, start_y) = grid.coordinates(start_index);
+
+    weights[start_index] = Some(0);
+    open_list.push(Reverse((grid.heuristic(start_x, start_y), start_index)));
+    stats.update_open_list_size(open_list.len());
+
+    while let Some(Reverse((_, current_index))) = open_list.pop() {
+        if closed[current_index] {
+            continue;
+        }
+
+        if current_index == end_index {
+            let road_cost = match connectivity {
+                Connectivity::Four => weights[end_index].unwrap() / STRAIGHT_MOVE_COST,
+                Connectivity::Eight => weights[end_index].unwrap()
+            };
+
+            return Ok(Path::with_cost(expand_jump_points(&grid, &parents, end_index), road_cost as usize));
+        }
+
+        closed[current_index] = true;
+        stats.nodes_expanded += 1;
+
+        let (x, y) = grid.coordinates(current_index);
+        let parent = parents[current_index].map(|parent_index| grid.coordinates(parent_index));
+        let current_weight = weights[current_index].unwrap();
+
+        for (neighbour_x, neighbour_y) in grid.neighbours(x, y, parent) {
+            let jump_point = grid.jump(neighbour_x, neighbour_y, neighbour_x - x, neighbour_y - y);
+
+            if let Some((jump_x, jump_y)) = jump_point {
+                let jump_index = grid.index(jump_x, jump_y);
+
+                if closed[jump_index] {
+                    continue;
+                }
+
+                let jump_weight = current_weight + move_cost((x, y), (jump_x, jump_y), connectivity);
+
+                if weights[jump_index].is_none_or(|weight| jump_weight < weight) {
+                    if weights[jump_index].is_some() {
+                        stats.reopened_nodes += 1;
+                    }
+
+                    weights[jump_index] = Some(jump_weight);
+                    parents[jump_index] = Some(current_index);
+                    open_list.push(Reverse((jump_weight + grid.heuristic(jump_x, jump_y), jump_index)));
+                    stats.nodes_generated += 1;
+                }
+            }
+        }
+
+        stats.update_open_list_size(open_list.len());
+    }
+
+    return Err("It seem that it has no end to this level");
+}
+
+/// Rebuild the road from the jump points and add all the fields between two jump points.
+fn expand_jump_points(grid: &JpsGrid, parents: &[Option<usize>], end_index: usize) -> Vec<Point> {
+    let mut jump_points: Vec<(i64, i64)> = vec![grid.coordinates(end_index)];
+    let mut current_index = end_index;
+
+    while let Some(parent_index) = parents[current_index] {
+        jump_points.push(grid.coordinates(parent_index));
+        current_index = parent_index;
+    }
+
+    jump_points.reverse();
+
+    let mut start_to_end_road: Vec<Point> = Vec::new();
+    let (first_x, first_y) = jump_points[0];
+    start_to_end_road.push(Point { x: Some(first_x as usize), y: Some(first_y as usize) });
+
+    for jump_segment in jump_points.windows(2) {
+        let (mut x, mut y) = jump_segment[0];
+        let (next_x, next_y) = jump_segment[1];
+        let (dx, dy) = ((next_x - x).signum(), (next_y - y).signum());
+
+        while (x, y) != (next_x, next_y) {
+            x += dx;
+            y += dy;
+            start_to_end_road.push(Point { x: Some(x as usize), y: Some(y as usize) });
+        }
+    }
+
+    return start_to_end_road;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::fs_aps_from_matrix;
+
+    #[test]
+    fn move_cost_test() {
+        assert_eq!(move_cost((0, 0), (2, 5), Connectivity::Four), 70);
+        assert_eq!(move_cost((0, 0), (2, 5), Connectivity::Eight), 58);
+    }
+
+    #[test]
+    fn jps_resolver_with_connectivity_no_corner_cutting_test() {
+        let matrix = vec![
+            vec![1, -1, 0],
+            vec![0, 0, 0],
+            vec![-1, 0, 2]
+        ];
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let start_end = (
+            Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(1) },
+            Field { coordinates: Point { x: Some(2), y: Some(2) }, value: Some(2) }
+        );
+
+        let road = jps_resolver_with_connectivity(fs.clone(), aps.clone(), 3, start_end, Connectivity::Eight).unwrap();
+
+        assert_eq!(road.points, vec![
+            Point { x: Some(0), y: Some(0) },
+            Point { x: Some(1), y: Some(0) },
+            Point { x: Some(1), y: Some(1) },
+            Point { x: Some(2), y: Some(2) }
+        ]);
+        assert_eq!(road.cost, 34);
+        assert_eq!(jps_resolver_with_connectivity(fs, aps, 3, start_end, Connectivity::Four).unwrap().cost, 4);
+    }
+}
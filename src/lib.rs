@@ -1,4 +1,27 @@
+#![allow(clippy::needless_return)]
+
 pub mod graph;
 pub mod a_star;
 pub mod dfs;
+pub mod bfs;
+pub mod jps;
+pub mod ida_star;
+pub mod ara_star;
+pub mod d_star_lite;
+pub mod theta_star;
+pub mod tsp;
+pub mod yen;
+pub mod stats;
+pub mod path;
+pub mod limits;
+pub mod turn_penalty;
+pub mod cbs;
+pub mod cooperative;
+pub mod timed_bord;
+pub mod hpa_star;
+pub mod distance_table;
+pub mod flow_field;
+pub mod path_validation;
+pub mod cost;
+mod space_time;
 pub mod chinese_rings;
\ No newline at end of file
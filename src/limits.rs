@@ -0,0 +1,148 @@
+//! # Description
+//! This is the search limits module.
+//! The `*_with_limits` resolvers stop when a limit is reached or when the search is cancelled,
+//! and return the results found until then marked as incomplete.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
+
+/// Limits of a search, `None` means no limit.
+/// `max_paths` is only used by the searches which return many paths.
+/// `max_depth` is the maximum number of moves of a path, the longer paths are not searched.
+/// `cancel_flag` can be set to `true` from another thread for stop the search.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+/// use std::sync::atomic::{ AtomicBool, Ordering };
+/// use esgi_arena_resolver_algorithms::limits::SearchLimits;
+///
+/// let cancel_flag = Arc::new(AtomicBool::new(false));
+/// let mut limits = SearchLimits::new();
+///
+/// limits.max_expansions = Some(1000);
+/// limits.cancel_flag = Some(cancel_flag.clone());
+///
+/// cancel_flag.store(true, Ordering::Relaxed);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SearchLimits {
+    pub max_expansions: Option<usize>,
+    pub max_paths: Option<usize>,
+    pub max_depth: Option<usize>,
+    pub deadline: Option<Instant>,
+    pub cancel_flag: Option<Arc<AtomicBool>>
+}
+
+/// The reason why a search stopped before the end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    MaxExpansions,
+    MaxPaths,
+    MaxDepth,
+    Deadline,
+    Cancelled
+}
+
+/// Result of a search with limits.
+/// When `is_complete` is false, `result` only contains what was found before the search stopped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LimitedSearch<T> {
+    pub result: T,
+    pub is_complete: bool,
+    pub stop_reason: Option<StopReason>
+}
+
+impl<T> LimitedSearch<T> {
+    pub fn complete(result: T) -> Self {
+        return Self {
+            result,
+            is_complete: true,
+            stop_reason: None
+        };
+    }
+
+    pub fn incomplete(result: T, stop_reason: StopReason) -> Self {
+        return Self {
+            result,
+            is_complete: false,
+            stop_reason: Some(stop_reason)
+        };
+    }
+}
+
+impl SearchLimits {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Get the reason to stop a search which has expanded `expansions` fields and found `paths_number` paths.
+    pub(crate) fn stop_reason(&self, expansions: usize, paths_number: usize) -> Option<StopReason> {
+        if self.cancel_flag.as_ref().is_some_and(|cancel_flag| cancel_flag.load(Ordering::Relaxed)) {
+            return Some(StopReason::Cancelled);
+        }
+
+        if self.max_expansions.is_some_and(|max_expansions| expansions >= max_expansions) {
+            return Some(StopReason::MaxExpansions);
+        }
+
+        if self.max_paths.is_some_and(|max_paths| paths_number >= max_paths) {
+            return Some(StopReason::MaxPaths);
+        }
+
+        if self.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            return Some(StopReason::Deadline);
+        }
+
+        return None;
+    }
+
+    /// Check that a path of `depth` moves is longer than the maximum depth.
+    pub(crate) fn is_too_deep(&self, depth: usize) -> bool {
+        return self.max_depth.is_some_and(|max_depth| depth > max_depth);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn stop_reason_test() {
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let mut limits = SearchLimits::new();
+
+        assert_eq!(limits.stop_reason(usize::MAX, usize::MAX), None);
+
+        limits.max_expansions = Some(10);
+        limits.max_paths = Some(2);
+        limits.cancel_flag = Some(cancel_flag.clone());
+
+        assert_eq!(limits.stop_reason(9, 1), None);
+        assert_eq!(limits.stop_reason(10, 1), Some(StopReason::MaxExpansions));
+        assert_eq!(limits.stop_reason(9, 2), Some(StopReason::MaxPaths));
+
+        limits.deadline = Some(Instant::now() - Duration::from_millis(1));
+
+        assert_eq!(limits.stop_reason(0, 0), Some(StopReason::Deadline));
+
+        cancel_flag.store(true, Ordering::Relaxed);
+
+        assert_eq!(limits.stop_reason(0, 0), Some(StopReason::Cancelled));
+    }
+
+    #[test]
+    fn is_too_deep_test() {
+        let mut limits = SearchLimits::new();
+
+        assert!(! limits.is_too_deep(usize::MAX));
+
+        limits.max_depth = Some(3);
+
+        assert!(! limits.is_too_deep(3));
+        assert!(limits.is_too_deep(4));
+    }
+}
@@ -0,0 +1,239 @@
+//! # Description
+//! This is the path handling module.
+//! A `Path` is the road found by a resolver with his cost.
+//! It can be converted in moves for drive a bot on the bord.
+
+pub use crate::graph::Point;
+
+/// Absolute move between two neighbour fields.
+/// `Up` and `Down` change the line (`x`), `Left` and `Right` change the column (`y`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    Up,
+    Down,
+    Left,
+    Right
+}
+
+/// Move relative to the previous move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Turn {
+    Straight,
+    Left,
+    Right,
+    Back
+}
+
+impl Move {
+    /// Get the move between two neighbour points.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use esgi_arena_resolver_algorithms::graph::Point;
+    /// use esgi_arena_resolver_algorithms::path::Move;
+    ///
+    /// let from = Point { x: Some(1), y: Some(1) };
+    ///
+    /// assert_eq!(Move::between(from, Point { x: Some(0), y: Some(1) }), Ok(Move::Up));
+    /// assert_eq!(Move::between(from, Point { x: Some(1), y: Some(2) }), Ok(Move::Right));
+    /// assert!(Move::between(from, Point { x: Some(2), y: Some(2) }).is_err());
+    /// ```
+    pub fn between(from: Point, to: Point) -> Result<Self, &'static str> {
+        let (from_x, from_y, to_x, to_y) = match (from.x, from.y, to.x, to.y) {
+            (Some(from_x), Some(from_y), Some(to_x), Some(to_y)) => (from_x, from_y, to_x, to_y),
+            _ => return Err("The parameters MUST be initializes")
+        };
+
+        return match (to_x as i64 - from_x as i64, to_y as i64 - from_y as i64) {
+            (-1, 0) => Ok(Move::Up),
+            (1, 0) => Ok(Move::Down),
+            (0, -1) => Ok(Move::Left),
+            (0, 1) => Ok(Move::Right),
+            _ => Err("The points of a path MUST be neighbours")
+        };
+    }
+
+    /// Get the turn for go from this move to the next one.
+    pub fn turn_to(self, next_move: Move) -> Turn {
+        return match (self, next_move) {
+            (Move::Up, Move::Up) | (Move::Down, Move::Down) | (Move::Left, Move::Left) | (Move::Right, Move::Right) => Turn::Straight,
+            (Move::Up, Move::Left) | (Move::Left, Move::Down) | (Move::Down, Move::Right) | (Move::Right, Move::Up) => Turn::Left,
+            (Move::Up, Move::Right) | (Move::Right, Move::Down) | (Move::Down, Move::Left) | (Move::Left, Move::Up) => Turn::Right,
+            _ => Turn::Back
+        };
+    }
+}
+
+/// Road found by a resolver.
+/// `points` are the fields from the start to the end and `cost` is the cost to follow them.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::Point;
+/// use esgi_arena_resolver_algorithms::path::{ Path, Move, Turn };
+///
+/// let path = Path::new(vec![
+///     Point { x: Some(0), y: Some(0) },
+///     Point { x: Some(0), y: Some(1) },
+///     Point { x: Some(1), y: Some(1) }
+/// ]);
+///
+/// assert_eq!(path.cost, 2);
+/// assert_eq!(path.len(), 3);
+/// assert_eq!(path.moves().unwrap(), vec![Move::Right, Move::Down]);
+/// assert_eq!(path.turns().unwrap(), vec![Turn::Right]);
+/// assert_eq!(path.clone().reverse().moves().unwrap(), vec![Move::Up, Move::Left]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Path {
+    pub points: Vec<Point>,
+    pub cost: usize
+}
+
+impl Path {
+    /// Create a path where each move cost 1.
+    pub fn new(points: Vec<Point>) -> Self {
+        return Self {
+            cost: points.len().saturating_sub(1),
+            points
+        };
+    }
+
+    pub fn with_cost(points: Vec<Point>, cost: usize) -> Self {
+        return Self {
+            points,
+            cost
+        };
+    }
+
+    /// Number of points of the path, the start and the end included.
+    pub fn len(&self) -> usize {
+        return self.points.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.points.is_empty();
+    }
+
+    pub fn first(&self) -> Option<&Point> {
+        return self.points.first();
+    }
+
+    pub fn last(&self) -> Option<&Point> {
+        return self.points.last();
+    }
+
+    /// Get the absolute moves for follow the path.
+    /// Only the horizontal and vertical moves are handled, a path with a diagonal move returns a error.
+    pub fn moves(&self) -> Result<Vec<Move>, &'static str> {
+        return self.points.windows(2)
+            .map(|points| Move::between(points[0], points[1]))
+            .collect();
+    }
+
+    /// Get the turns between each move of the path.
+    /// There is one turn less than the number of moves.
+    pub fn turns(&self) -> Result<Vec<Turn>, &'static str> {
+        let moves = self.moves()?;
+
+        return Ok(moves.windows(2).map(|moves| moves[0].turn_to(moves[1])).collect());
+    }
+
+    /// Get the same path from the end to the start.
+    pub fn reverse(mut self) -> Self {
+        self.points.reverse();
+
+        return self;
+    }
+
+    /// Add a path after this one.
+    /// The next path MUST start where this one ends, the common point is kept once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use esgi_arena_resolver_algorithms::graph::Point;
+    /// use esgi_arena_resolver_algorithms::path::Path;
+    ///
+    /// let first_path = Path::new(vec![Point { x: Some(0), y: Some(0) }, Point { x: Some(0), y: Some(1) }]);
+    /// let next_path = Path::new(vec![Point { x: Some(0), y: Some(1) }, Point { x: Some(1), y: Some(1) }]);
+    ///
+    /// let path = first_path.concat(next_path).unwrap();
+    ///
+    /// assert_eq!(path.len(), 3);
+    /// assert_eq!(path.cost, 2);
+    /// ```
+    pub fn concat(mut self, next_path: Path) -> Result<Self, &'static str> {
+        if self.is_empty() {
+            return Ok(next_path);
+        }
+
+        if next_path.is_empty() {
+            return Ok(self);
+        }
+
+        if self.last() != next_path.first() {
+            return Err("The next path MUST start at the end of the path");
+        }
+
+        self.points.extend(next_path.points.into_iter().skip(1));
+        self.cost += next_path.cost;
+
+        return Ok(self);
+    }
+
+    /// Check that the path can be followed on a bord:
+    /// all the points are inside the bord, no point is a wall (`-1`) and each point is a neighbour of the previous one.
+    /// The neighbours are only the horizontal and vertical ones, so a path with a diagonal move is not valid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use esgi_arena_resolver_algorithms::graph::Point;
+    /// use esgi_arena_resolver_algorithms::path::Path;
+    ///
+    /// let matrix = vec![
+    ///     vec![1, -1],
+    ///     vec![0, 2]
+    /// ];
+    ///
+    /// assert!(Path::new(vec![Point { x: Some(0), y: Some(0) }, Point { x: Some(1), y: Some(0) }, Point { x: Some(1), y: Some(1) }]).is_valid_on(&matrix));
+    /// assert!(! Path::new(vec![Point { x: Some(0), y: Some(0) }, Point { x: Some(0), y: Some(1) }, Point { x: Some(1), y: Some(1) }]).is_valid_on(&matrix));
+    /// ```
+    pub fn is_valid_on(&self, matrix_bord: &[Vec<i8>]) -> bool {
+        if self.is_empty() || self.moves().is_err() {
+            return false;
+        }
+
+        return self.points.iter().all(|point| {
+            return match (point.x, point.y) {
+                (Some(x), Some(y)) => matrix_bord.get(x).and_then(|line| line.get(y)).is_some_and(|value| *value != -1),
+                _ => false
+            };
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turn_to_test() {
+        assert_eq!(Move::Up.turn_to(Move::Up), Turn::Straight);
+        assert_eq!(Move::Up.turn_to(Move::Left), Turn::Left);
+        assert_eq!(Move::Left.turn_to(Move::Up), Turn::Right);
+        assert_eq!(Move::Down.turn_to(Move::Up), Turn::Back);
+    }
+
+    #[test]
+    fn concat_not_joined_test() {
+        let first_path = Path::new(vec![Point { x: Some(0), y: Some(0) }, Point { x: Some(0), y: Some(1) }]);
+        let next_path = Path::with_cost(vec![Point { x: Some(1), y: Some(0) }, Point { x: Some(1), y: Some(1) }], 3);
+
+        assert!(first_path.clone().concat(next_path.clone()).is_err());
+        assert_eq!(Path::default().concat(next_path.clone()), Ok(next_path));
+    }
+}
@@ -0,0 +1,201 @@
+//! # Description
+//! This is the path validation module, for check the roads sent by the player bots.
+//! A road is checked step by step on the bord matrix and the first violation is reported,
+//! then the cost of a valid road is compared with the cost of the A* road to the nearest end.
+
+pub use crate::graph::{Field, Point, fs_aps_from_matrix};
+use crate::path::{Path, Move};
+use crate::a_star::a_star_nearest_road_resolver;
+
+/// The first reason why a road cannot be followed on a bord.
+/// `step` is the index of the wrong point inside the road.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathViolation {
+    EmptyPath,
+    OutsideBord { step: usize },
+    Wall { step: usize },
+    NotNeighbour { step: usize },
+    WrongStart,
+    WrongEnd
+}
+
+/// The result of the validation of a road.
+/// `cost` is the number of moves of the road.
+/// `optimal_cost` and `is_optimal` are only computed for a road without violation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathReport {
+    pub violation: Option<PathViolation>,
+    pub cost: usize,
+    pub optimal_cost: Option<usize>,
+    pub is_optimal: bool
+}
+
+impl PathReport {
+    pub fn is_valid(&self) -> bool {
+        return self.violation.is_none();
+    }
+}
+
+/// Get the first violation of a road on a bord, `None` when the road can be followed from the start (`1`) to an end (`2`).
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::Point;
+/// use esgi_arena_resolver_algorithms::path_validation::{ get_path_violation, PathViolation };
+///
+/// let matrix = vec![
+///     vec![1, -1],
+///     vec![0, 2]
+/// ];
+///
+/// assert_eq!(get_path_violation(&matrix, &[Point { x: Some(0), y: Some(0) }, Point { x: Some(1), y: Some(0) }, Point { x: Some(1), y: Some(1) }]), None);
+/// assert_eq!(get_path_violation(&matrix, &[Point { x: Some(0), y: Some(0) }, Point { x: Some(0), y: Some(1) }]), Some(PathViolation::Wall { step: 1 }));
+/// assert_eq!(get_path_violation(&matrix, &[Point { x: Some(0), y: Some(0) }, Point { x: Some(1), y: Some(1) }]), Some(PathViolation::NotNeighbour { step: 1 }));
+/// assert_eq!(get_path_violation(&matrix, &[Point { x: Some(0), y: Some(0) }, Point { x: Some(1), y: Some(0) }]), Some(PathViolation::WrongEnd));
+/// ```
+pub fn get_path_violation(matrix_bord: &[Vec<i8>], points: &[Point]) -> Option<PathViolation> {
+    if points.is_empty() {
+        return Some(PathViolation::EmptyPath);
+    }
+
+    for (step, point) in points.iter().enumerate() {
+        let value = match (point.x, point.y) {
+            (Some(x), Some(y)) => matrix_bord.get(x).and_then(|line| line.get(y)).cloned(),
+            _ => None
+        };
+
+        match value {
+            None => return Some(PathViolation::OutsideBord { step }),
+            Some(-1) => return Some(PathViolation::Wall { step }),
+            Some(value) if step == 0 && value != 1 => return Some(PathViolation::WrongStart),
+            _ => {}
+        }
+
+        if step > 0 && Move::between(points[step - 1], *point).is_err() {
+            return Some(PathViolation::NotNeighbour { step });
+        }
+    }
+
+    let last_point = points[points.len() - 1];
+
+    if matrix_bord[last_point.x.unwrap()][last_point.y.unwrap()] != 2 {
+        return Some(PathViolation::WrongEnd);
+    }
+
+    return None;
+}
+
+/// Path validation function.
+/// It's check a road sent for a bord, compute his cost and compare it with the cost of the `a_star_nearest_road_resolver` road
+/// from the same start to all the ends of the bord. The road is optimal when no road from the start to an end is shorter,
+/// even a road to another end than the last point of the road.
+/// It's return an error only when the bord matrix cannot be used.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::Point;
+/// use esgi_arena_resolver_algorithms::path_validation::{ validate_path, PathViolation };
+///
+/// let matrix = vec![
+///     vec![1, 0, 0],
+///     vec![0, -1, 0],
+///     vec![0, 0, 2]
+/// ];
+/// let road = vec![
+///     Point { x: Some(0), y: Some(0) },
+///     Point { x: Some(0), y: Some(1) },
+///     Point { x: Some(0), y: Some(0) },
+///     Point { x: Some(1), y: Some(0) },
+///     Point { x: Some(2), y: Some(0) },
+///     Point { x: Some(2), y: Some(1) },
+///     Point { x: Some(2), y: Some(2) }
+/// ];
+///
+/// let report = validate_path(&matrix, &road).unwrap();
+///
+/// assert!(report.is_valid());
+/// assert_eq!(report.cost, 6);
+/// assert_eq!(report.optimal_cost, Some(4));
+/// assert!(! report.is_optimal);
+///
+/// let report = validate_path(&matrix, &road[1..]).unwrap();
+///
+/// assert_eq!(report.violation, Some(PathViolation::WrongStart));
+/// ```
+pub fn validate_path(matrix_bord: &[Vec<i8>], points: &[Point]) -> Result<PathReport, &'static str> {
+    let (fs, aps) = fs_aps_from_matrix(matrix_bord.to_vec())?;
+    let violation = get_path_violation(matrix_bord, points);
+    let cost = Path::new(points.to_vec()).cost;
+
+    if violation.is_some() {
+        return Ok(PathReport {
+            violation,
+            cost,
+            optimal_cost: None,
+            is_optimal: false
+        });
+    }
+
+    let mut end_points: Vec<Field> = Vec::new();
+
+    for (x, line) in matrix_bord.iter().enumerate() {
+        for (y, value) in line.iter().enumerate() {
+            if *value == 2 {
+                end_points.push(Field { coordinates: Point { x: Some(x), y: Some(y) }, value: Some(2) });
+            }
+        }
+    }
+
+    let start_point = Field { coordinates: points[0], value: Some(1) };
+    let optimal_cost = a_star_nearest_road_resolver(fs, aps, matrix_bord.len(), (start_point, end_points))?.cost;
+
+    return Ok(PathReport {
+        violation,
+        cost,
+        optimal_cost: Some(optimal_cost),
+        is_optimal: cost == optimal_cost
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_path_violation_order_test() {
+        let matrix = vec![
+            vec![1, 0],
+            vec![-1, 2]
+        ];
+
+        assert_eq!(get_path_violation(&matrix, &[]), Some(PathViolation::EmptyPath));
+        assert_eq!(get_path_violation(&matrix, &[Point { x: Some(1), y: Some(0) }]), Some(PathViolation::Wall { step: 0 }));
+        assert_eq!(get_path_violation(&matrix, &[Point { x: Some(0), y: Some(1) }, Point { x: Some(5), y: Some(1) }]), Some(PathViolation::WrongStart));
+        assert_eq!(get_path_violation(&matrix, &[Point { x: Some(0), y: Some(0) }, Point { x: Some(0), y: None }]), Some(PathViolation::OutsideBord { step: 1 }));
+        assert_eq!(get_path_violation(&matrix, &[Point { x: Some(0), y: Some(0) }, Point { x: Some(0), y: Some(0) }]), Some(PathViolation::NotNeighbour { step: 1 }));
+    }
+
+    #[test]
+    fn validate_path_nearest_end_test() {
+        let matrix = vec![
+            vec![2, 0, 0, 0],
+            vec![1, 0, 0, 0],
+            vec![0, 0, 0, 0],
+            vec![0, 0, 0, 2]
+        ];
+        let far_road: Vec<Point> = vec![(1, 0), (2, 0), (3, 0), (3, 1), (3, 2), (3, 3)].into_iter()
+            .map(|(x, y)| Point { x: Some(x), y: Some(y) })
+            .collect();
+
+        let report = validate_path(&matrix, &far_road).unwrap();
+
+        assert!(report.is_valid());
+        assert_eq!((report.cost, report.optimal_cost, report.is_optimal), (5, Some(1), false));
+
+        let near_road = vec![Point { x: Some(1), y: Some(0) }, Point { x: Some(0), y: Some(0) }];
+
+        assert!(validate_path(&matrix, &near_road).unwrap().is_optimal);
+    }
+}
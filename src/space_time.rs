@@ -0,0 +1,182 @@
+//! # Description
+//! This is the space-time A* module shared by the multi-agent resolvers.
+//! A state of the search is a field at a timestep, each action (a move or a wait on the same field) takes one timestep.
+//! The heuristic is the distance to the end without any constraint, computed with a BFS from the end.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::graph::{Field, Point, get_element_childs_slice};
+use crate::bfs::bfs_search;
+use crate::stats::SearchStats;
+
+/// The parameters of a space-time search.
+/// The road can stop on the end only from `goal_min_time`.
+/// When `window_end` is given, a state at this timestep also stops the search:
+/// its cost is the timestep plus the distance left to the end.
+/// When `periodic_times` is given as `(first_periodic_time, period)`, the allowed moves come back with the period
+/// from the first periodic time, so a field is expanded only once for each phase of the period after this time.
+/// The goal MUST be allowed from a timestep which is not after the first periodic time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SpaceTimeQuery {
+    pub start_index: usize,
+    pub start_time: usize,
+    pub end_index: usize,
+    pub goal_min_time: usize,
+    pub max_time: usize,
+    pub window_end: Option<usize>,
+    pub periodic_times: Option<(usize, usize)>
+}
+
+impl SpaceTimeQuery {
+    /// Get the state which is closed when a field is expanded at a timestep.
+    /// After the first periodic time, all the timesteps of the same phase close the same state.
+    fn get_closed_state(&self, index: usize, time: usize) -> (usize, usize) {
+        return match self.periodic_times {
+            Some((first_periodic_time, period)) if time >= first_periodic_time => (index, first_periodic_time + (time - first_periodic_time) % period),
+            _ => (index, time)
+        };
+    }
+}
+
+/// Get the road with the smaller number of timesteps from the start to the end.
+/// `is_allowed_move` receive the field index before the action, the field index after the action and the timestep after the action.
+/// The road gives the field index for each timestep from `start_time`.
+/// The expanded and generated states are added to the statistics, so the searches of many agents can fill the same statistics.
+/// The BFS of the heuristic is not counted.
+pub(crate) fn space_time_a_star(fs: &[Field], aps: &[u32], matrix_size: usize, query: &SpaceTimeQuery, is_allowed_move: &dyn Fn(usize, usize, usize) -> bool, stats: &mut SearchStats) -> Result<Option<Vec<usize>>, &'static str> {
+    let (distances, _) = bfs_search(fs, aps, matrix_size, query.end_index, None, &|_, _| true)?;
+
+    let start_distance = match distances.get(query.start_index).cloned().flatten() {
+        Some(start_distance) => start_distance,
+        None => return Ok(None)
+    };
+
+    let start_state = (query.start_index, query.start_time);
+    let mut parents: HashMap<(usize, usize), (usize, usize)> = HashMap::new();
+    let mut closed: HashSet<(usize, usize)> = HashSet::new();
+    let mut open_list: BinaryHeap<Reverse<(usize, Reverse<usize>, usize)>> = BinaryHeap::new();
+
+    open_list.push(Reverse((start_distance, Reverse(query.start_time), query.start_index)));
+    stats.update_open_list_size(open_list.len());
+
+    while let Some(Reverse((_, Reverse(time), index))) = open_list.pop() {
+        if ! closed.insert(query.get_closed_state(index, time)) {
+            continue;
+        }
+
+        stats.nodes_expanded += 1;
+
+        let is_goal = index == query.end_index && time >= query.goal_min_time;
+
+        if is_goal || query.window_end.is_some_and(|window_end| time >= window_end) {
+            let mut road: Vec<usize> = vec![index];
+            let mut state = (index, time);
+
+            while state != start_state {
+                state = parents[&state];
+                road.push(state.0);
+            }
+
+            road.reverse();
+
+            return Ok(Some(road));
+        }
+
+        if time >= query.max_time {
+            continue;
+        }
+
+        let childs = get_element_childs_slice(fs, aps, index)?;
+        let wait_field = Field { coordinates: Point::from_index(index, matrix_size)?, value: Some(0) };
+
+        for child in childs.iter().chain(std::iter::once(&wait_field)) {
+            if child.value == Some(-1) {
+                continue;
+            }
+
+            let child_index = child.coordinates.get_index(matrix_size)?;
+            let child_state = (child_index, time + 1);
+
+            if closed.contains(&query.get_closed_state(child_index, time + 1)) || parents.contains_key(&child_state) || ! is_allowed_move(index, child_index, time + 1) {
+                continue;
+            }
+
+            let child_distance = match distances.get(child_index).cloned().flatten() {
+                Some(child_distance) => child_distance,
+                None => continue
+            };
+
+            parents.insert(child_state, (index, time));
+            open_list.push(Reverse((time + 1 - query.start_time + child_distance, Reverse(time + 1), child_index)));
+            stats.nodes_generated += 1;
+        }
+
+        stats.update_open_list_size(open_list.len());
+    }
+
+    return Ok(None);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::fs_aps_from_matrix;
+
+    #[test]
+    fn space_time_a_star_wait_test() {
+        let matrix = vec![
+            vec![1, 0, 2],
+            vec![-1, -1, -1],
+            vec![-1, -1, -1]
+        ];
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let query = SpaceTimeQuery { start_index: 0, start_time: 0, end_index: 2, goal_min_time: 0, max_time: 10, window_end: None, periodic_times: None };
+
+        assert_eq!(space_time_a_star(&fs, &aps, 3, &query, &|_, _, _| true, &mut SearchStats::new()).unwrap(), Some(vec![0, 1, 2]));
+
+        let is_allowed_move = |_: usize, to_index: usize, time: usize| ! (to_index == 1 && time <= 2);
+
+        assert_eq!(space_time_a_star(&fs, &aps, 3, &query, &is_allowed_move, &mut SearchStats::new()).unwrap(), Some(vec![0, 0, 0, 1, 2]));
+
+        let late_query = SpaceTimeQuery { goal_min_time: 4, ..query };
+
+        assert_eq!(space_time_a_star(&fs, &aps, 3, &late_query, &|_, _, _| true, &mut SearchStats::new()).unwrap().map(|road| road.len()), Some(5));
+    }
+
+    #[test]
+    fn space_time_a_star_window_test() {
+        let matrix = vec![
+            vec![1, 0, 0],
+            vec![-1, -1, 0],
+            vec![2, 0, 0]
+        ];
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let query = SpaceTimeQuery { start_index: 0, start_time: 3, end_index: 6, goal_min_time: 0, max_time: 20, window_end: Some(5), periodic_times: None };
+
+        assert_eq!(space_time_a_star(&fs, &aps, 3, &query, &|_, _, _| true, &mut SearchStats::new()).unwrap(), Some(vec![0, 1, 2]));
+
+        let blocked_query = SpaceTimeQuery { max_time: 4, window_end: None, ..query };
+
+        assert_eq!(space_time_a_star(&fs, &aps, 3, &blocked_query, &|_, _, _| true, &mut SearchStats::new()).unwrap(), None);
+    }
+
+    #[test]
+    fn space_time_a_star_periodic_test() {
+        let matrix = vec![
+            vec![1, 0, 2],
+            vec![-1, -1, -1],
+            vec![-1, -1, -1]
+        ];
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let is_allowed_move = |_: usize, to_index: usize, time: usize| ! ((to_index == 1 || to_index == 2) && time % 2 == 1);
+        let query = SpaceTimeQuery { start_index: 0, start_time: 0, end_index: 2, goal_min_time: 0, max_time: usize::MAX, window_end: None, periodic_times: Some((0, 2)) };
+
+        assert_eq!(space_time_a_star(&fs, &aps, 3, &query, &is_allowed_move, &mut SearchStats::new()).unwrap(), None);
+
+        let is_allowed_move = |_: usize, to_index: usize, time: usize| ! (to_index == 1 && time % 3 != 2);
+        let query = SpaceTimeQuery { periodic_times: Some((0, 3)), ..query };
+
+        assert_eq!(space_time_a_star(&fs, &aps, 3, &query, &is_allowed_move, &mut SearchStats::new()).unwrap(), Some(vec![0, 0, 1, 2]));
+    }
+}
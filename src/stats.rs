@@ -0,0 +1,158 @@
+//! # Description
+//! This is the search statistics module.
+//! The `*_with_stats` resolvers fill a `SearchStats` for profile the levels and compare the algorithms.
+//! The statistics are filled even when no road is found.
+
+use std::time::{Duration, Instant};
+
+use crate::path::Path;
+use crate::graph::Point;
+
+/// Statistics of one search.
+/// `nodes_expanded` is the number of fields taken from the open list (or visited by the DFS).
+/// `nodes_generated` is the number of children added to the open list.
+/// `max_open_list_size` is the bigger size of the open list (or of the DFS stack) during the search.
+/// `reopened_nodes` is the number of fields added again to the open list with a smaller cost.
+/// `path_cost` is the cost of the road found, or of the shorter one for the DFS,
+/// and the sum of the costs of the agents roads for the resolvers of many agents.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::a_star::a_star_resolver_with_stats;
+/// use esgi_arena_resolver_algorithms::stats::SearchStats;
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![2, 0, 0],
+///     vec![-1, -1, 0],
+///     vec![1, 0, 0]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(2), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(2)
+///     }
+/// );
+/// let mut stats = SearchStats::new();
+///
+/// a_star_resolver_with_stats(fs_example, aps_example, matrix_example.len(), start_end_fields, &mut stats).unwrap();
+///
+/// assert_eq!(stats.path_cost, Some(6));
+/// assert_eq!(stats.nodes_expanded, 7);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SearchStats {
+    pub nodes_expanded: usize,
+    pub nodes_generated: usize,
+    pub max_open_list_size: usize,
+    pub reopened_nodes: usize,
+    pub path_cost: Option<usize>,
+    pub elapsed: Duration
+}
+
+impl SearchStats {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Keep the bigger size of the open list.
+    pub(crate) fn update_open_list_size(&mut self, open_list_size: usize) {
+        self.max_open_list_size = self.max_open_list_size.max(open_list_size);
+    }
+
+    /// Set the elapsed time since the start of the search.
+    pub(crate) fn stop_timer(&mut self, start_time: Instant) {
+        self.elapsed = start_time.elapsed();
+    }
+}
+
+/// Road which can give his cost to the statistics.
+/// A list of roads give the cost of the shorter one.
+pub(crate) trait RoadCost {
+    fn road_cost(&self) -> Option<usize>;
+}
+
+impl RoadCost for Path {
+    fn road_cost(&self) -> Option<usize> {
+        return Some(self.cost);
+    }
+}
+
+impl<R: RoadCost> RoadCost for Vec<R> {
+    fn road_cost(&self) -> Option<usize> {
+        return self.iter().filter_map(|road| road.road_cost()).min();
+    }
+}
+
+/// The roads of many agents, one point for each timestep, give the sum of their costs.
+impl RoadCost for Vec<Vec<Point>> {
+    fn road_cost(&self) -> Option<usize> {
+        return Some(self.iter().map(|road| road.len().saturating_sub(1)).sum());
+    }
+}
+
+/// Reset the statistics and run a search which return one road or a list of roads.
+/// The elapsed time and the road cost are filled after the search.
+pub(crate) fn record_road_search<R: RoadCost>(stats: &mut SearchStats, search: impl FnOnce(&mut SearchStats) -> Result<R, &'static str>) -> Result<R, &'static str> {
+    let start_time = Instant::now();
+
+    *stats = SearchStats::new();
+
+    let road = search(stats);
+
+    stats.stop_timer(start_time);
+    stats.path_cost = road.as_ref().ok().and_then(|road| road.road_cost());
+
+    return road;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_road_search_test() {
+        let mut stats = SearchStats::new();
+
+        stats.nodes_expanded = 10;
+
+        let road = record_road_search(&mut stats, |stats| {
+            stats.nodes_expanded += 1;
+            return Ok(Path::new(vec![Point::new(), Point::new(), Point::new()]));
+        });
+
+        assert_eq!(road.unwrap().len(), 3);
+        assert_eq!(stats.nodes_expanded, 1);
+        assert_eq!(stats.path_cost, Some(2));
+
+        let no_road: Result<Path, &'static str> = record_road_search(&mut stats, |_| Err("It seem that it has no end to this level"));
+
+        assert!(no_road.is_err());
+        assert_eq!(stats.path_cost, None);
+
+        let roads = record_road_search(&mut stats, |_| Ok(vec![Path::with_cost(Vec::new(), 4), Path::with_cost(Vec::new(), 3)]));
+
+        assert_eq!(roads.unwrap().len(), 2);
+        assert_eq!(stats.path_cost, Some(3));
+
+        let agents_roads = record_road_search(&mut stats, |_| Ok(vec![vec![Point::new(); 3], vec![Point::new(); 5]]));
+
+        assert_eq!(agents_roads.unwrap().len(), 2);
+        assert_eq!(stats.path_cost, Some(6));
+    }
+
+    #[test]
+    fn update_open_list_size_test() {
+        let mut stats = SearchStats::new();
+
+        stats.update_open_list_size(3);
+        stats.update_open_list_size(1);
+
+        assert_eq!(stats.max_open_list_size, 3);
+    }
+}
@@ -0,0 +1,371 @@
+//! # Description
+//! This is the Theta* algorithm module.
+//! The Theta* is a A* where a field can take the parent of his parent as parent when there is
+//! no wall between them, so the road is not limited to the moves between neighbours.
+//! The road returned only contains the waypoints where the direction changes.
+//! [For more explainations](https://en.wikipedia.org/wiki/Theta*)
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+pub use crate::graph::{Field, Point};
+pub use crate::path::Path;
+pub use crate::stats::SearchStats;
+use crate::graph::get_element_childs_slice;
+use crate::stats::record_road_search;
+
+/// Field waiting inside the open list with his cost.
+/// The order is reversed for get the smaller cost first from a `BinaryHeap`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ThetaStarOpenField {
+    cost: f64,
+    index: usize
+}
+
+impl Eq for ThetaStarOpenField {}
+
+impl PartialOrd for ThetaStarOpenField {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for ThetaStarOpenField {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return other.cost.total_cmp(&self.cost).then_with(|| other.index.cmp(&self.index));
+    }
+}
+
+/// Get the straight line distance between the centers of two fields.
+fn get_euclidean_distance(from: (i64, i64), to: (i64, i64)) -> f64 {
+    return (((from.0 - to.0).pow(2) + (from.1 - to.1).pow(2)) as f64).sqrt();
+}
+
+/// Check that the straight line between the centers of two fields never cross a wall.
+/// When the line pass exactly by the corner of two fields, both fields must be free.
+fn line_of_sight(is_wall: &dyn Fn(i64, i64) -> bool, from: (i64, i64), to: (i64, i64)) -> bool {
+    let (mut x, mut y) = from;
+    let (x_distance, y_distance) = ((to.0 - from.0).abs(), (to.1 - from.1).abs());
+    let (x_step, y_step) = ((to.0 - from.0).signum(), (to.1 - from.1).signum());
+    let (double_x_distance, double_y_distance) = (2 * x_distance, 2 * y_distance);
+
+    if is_wall(x, y) {
+        return false;
+    }
+
+    if double_x_distance >= double_y_distance {
+        let mut error = x_distance;
+        let mut previous_error = error;
+
+        for _ in 0..x_distance {
+            x += x_step;
+            error += double_y_distance;
+
+            if error > double_x_distance {
+                y += y_step;
+                error -= double_x_distance;
+
+                let crossed_fields = match (error + previous_error).cmp(&double_x_distance) {
+                    Ordering::Less => is_wall(x, y - y_step),
+                    Ordering::Greater => is_wall(x - x_step, y),
+                    Ordering::Equal => is_wall(x, y - y_step) || is_wall(x - x_step, y)
+                };
+
+                if crossed_fields {
+                    return false;
+                }
+            }
+
+            if is_wall(x, y) {
+                return false;
+            }
+
+            previous_error = error;
+        }
+    } else {
+        let mut error = y_distance;
+        let mut previous_error = error;
+
+        for _ in 0..y_distance {
+            y += y_step;
+            error += double_x_distance;
+
+            if error > double_y_distance {
+                x += x_step;
+                error -= double_y_distance;
+
+                let crossed_fields = match (error + previous_error).cmp(&double_y_distance) {
+                    Ordering::Less => is_wall(x - x_step, y),
+                    Ordering::Greater => is_wall(x, y - y_step),
+                    Ordering::Equal => is_wall(x - x_step, y) || is_wall(x, y - y_step)
+                };
+
+                if crossed_fields {
+                    return false;
+                }
+            }
+
+            if is_wall(x, y) {
+                return false;
+            }
+
+            previous_error = error;
+        }
+    }
+
+    return true;
+}
+
+/// Check that the straight line between the centers of two fields of a bord never cross a wall (`-1`).
+/// The outside of the bord is considered as a wall.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::Point;
+/// use esgi_arena_resolver_algorithms::theta_star::has_line_of_sight;
+///
+/// let matrix = vec![
+///     vec![1, 0, 0],
+///     vec![0, -1, 0],
+///     vec![0, 0, 2]
+/// ];
+///
+/// assert!(has_line_of_sight(&matrix, Point { x: Some(0), y: Some(0) }, Point { x: Some(0), y: Some(2) }));
+/// assert!(! has_line_of_sight(&matrix, Point { x: Some(0), y: Some(0) }, Point { x: Some(2), y: Some(2) }));
+/// ```
+pub fn has_line_of_sight(matrix_bord: &[Vec<i8>], from: Point, to: Point) -> bool {
+    let (from_x, from_y, to_x, to_y) = match (from.x, from.y, to.x, to.y) {
+        (Some(from_x), Some(from_y), Some(to_x), Some(to_y)) => (from_x as i64, from_y as i64, to_x as i64, to_y as i64),
+        _ => return false
+    };
+    let is_wall = |x: i64, y: i64| -> bool {
+        if x < 0 || y < 0 {
+            return true;
+        }
+
+        return matrix_bord.get(x as usize).and_then(|line| line.get(y as usize)).is_none_or(|value| *value == -1);
+    };
+
+    return line_of_sight(&is_wall, (from_x, from_y), (to_x, to_y));
+}
+
+/// Theta* resolver function.
+/// It's find a short any-angle road between two points of a graph.
+/// The returned road only contains the waypoints, each waypoint can see the next one without any wall between them.
+/// The waypoints are not always neighbours, so the cost of the road is his straight line length rounded up.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::theta_star::theta_star_resolver;
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![1, 0, 0, 0],
+///     vec![0, 0, 0, 0],
+///     vec![0, 0, 0, 0],
+///     vec![0, 0, 0, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(3), y: Some(3) },
+///         value: Some(2)
+///     }
+/// );
+///
+/// let road = theta_star_resolver(fs_example, aps_example, matrix_example.len(), start_end_fields).unwrap();
+///
+/// assert_eq!(road.points, vec![
+///     Point { x: Some(0), y: Some(0) },
+///     Point { x: Some(3), y: Some(3) }
+/// ]);
+/// assert_eq!(road.cost, 5);
+/// ```
+pub fn theta_star_resolver(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field)) -> Result<Path, &'static str> {
+    return theta_star_resolver_with_stats(fs, aps, matrix_size, start_end_point, &mut SearchStats::new());
+}
+
+/// Theta* resolver function which fill the search statistics.
+/// The road returned is the same as the `theta_star_resolver` function.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::theta_star::{ theta_star_resolver_with_stats, SearchStats };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![1, 0, 0, 0],
+///     vec![0, 0, 0, 0],
+///     vec![0, 0, 0, 0],
+///     vec![0, 0, 0, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(3), y: Some(3) },
+///         value: Some(2)
+///     }
+/// );
+/// let mut stats = SearchStats::new();
+///
+/// theta_star_resolver_with_stats(fs_example, aps_example, matrix_example.len(), start_end_fields, &mut stats).unwrap();
+///
+/// assert_eq!(stats.path_cost, Some(5));
+/// assert!(stats.nodes_expanded > 0);
+/// assert!(stats.nodes_generated >= stats.nodes_expanded);
+/// ```
+pub fn theta_star_resolver_with_stats(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), stats: &mut SearchStats) -> Result<Path, &'static str> {
+    return record_road_search(stats, |stats| theta_star_search(fs, aps, matrix_size, start_end_point, stats));
+}
+
+fn theta_star_search(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), stats: &mut SearchStats) -> Result<Path, &'static str> {
+    if fs.is_empty() || aps.is_empty() || matrix_size == 0 || start_end_point == (Field::new(), Field::new()) {
+        return Err("The parameters MUST be initializes");
+    }
+
+    let (start_point, end_point) = start_end_point;
+    let fields_number = aps.len() - 1;
+    let start_index = start_point.coordinates.get_index(matrix_size)?;
+    let end_index = end_point.coordinates.get_index(matrix_size)?;
+
+    if start_index >= fields_number || end_index >= fields_number {
+        return Err("The target point cannot be found inside the matrix");
+    }
+
+    let mut walls: Vec<bool> = vec![true; fields_number];
+
+    for field in fs.iter() {
+        let field_index = field.coordinates.get_index(matrix_size)?;
+
+        if field_index < fields_number {
+            walls[field_index] = field.value.is_none() || field.value == Some(-1);
+        }
+    }
+
+    walls[start_index] = false;
+
+    let is_wall = |x: i64, y: i64| -> bool {
+        if x < 0 || y < 0 || x >= matrix_size as i64 || y >= matrix_size as i64 {
+            return true;
+        }
+
+        return walls.get(x as usize * matrix_size + y as usize).cloned().unwrap_or(true);
+    };
+    let coordinates = |index: usize| -> (i64, i64) { ((index / matrix_size) as i64, (index % matrix_size) as i64) };
+    let end_coordinates = coordinates(end_index);
+
+    let mut costs: Vec<Option<f64>> = vec![None; fields_number];
+    let mut parents: Vec<Option<usize>> = vec![None; fields_number];
+    let mut closed: Vec<bool> = vec![false; fields_number];
+    let mut open_list: BinaryHeap<ThetaStarOpenField> = BinaryHeap::new();
+
+    costs[start_index] = Some(0.0);
+    open_list.push(ThetaStarOpenField { cost: get_euclidean_distance(coordinates(start_index), end_coordinates), index: start_index });
+    stats.update_open_list_size(open_list.len());
+
+    while let Some(ThetaStarOpenField { index: current_index, .. }) = open_list.pop() {
+        if closed[current_index] {
+            continue;
+        }
+
+        if current_index == end_index {
+            let mut waypoints: Vec<Point> = vec![Point::from_index(end_index, matrix_size)?];
+            let mut waypoint_index = end_index;
+
+            while let Some(parent_index) = parents[waypoint_index] {
+                waypoints.push(Point::from_index(parent_index, matrix_size)?);
+                waypoint_index = parent_index;
+            }
+
+            waypoints.reverse();
+
+            return Ok(Path::with_cost(waypoints, costs[end_index].unwrap().ceil() as usize));
+        }
+
+        closed[current_index] = true;
+        stats.nodes_expanded += 1;
+
+        for child in get_element_childs_slice(&fs, &aps, current_index)? {
+            let child_index = child.coordinates.get_index(matrix_size)?;
+
+            if child.value == Some(-1) || child_index >= fields_number || closed[child_index] {
+                continue;
+            }
+
+            let child_coordinates = coordinates(child_index);
+            let (parent_index, parent_cost) = match parents[current_index] {
+                Some(grand_parent_index) if line_of_sight(&is_wall, coordinates(grand_parent_index), child_coordinates) => {
+                    (grand_parent_index, costs[grand_parent_index].unwrap())
+                },
+                _ => (current_index, costs[current_index].unwrap())
+            };
+            let child_cost = parent_cost + get_euclidean_distance(coordinates(parent_index), child_coordinates);
+
+            if costs[child_index].is_none_or(|cost| child_cost < cost) {
+                if costs[child_index].is_some() {
+                    stats.reopened_nodes += 1;
+                }
+
+                costs[child_index] = Some(child_cost);
+                parents[child_index] = Some(parent_index);
+                open_list.push(ThetaStarOpenField { cost: child_cost + get_euclidean_distance(child_coordinates, end_coordinates), index: child_index });
+                stats.nodes_generated += 1;
+            }
+        }
+
+        stats.update_open_list_size(open_list.len());
+    }
+
+    return Err("It seem that it has no end to this level");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::fs_aps_from_matrix;
+
+    #[test]
+    fn line_of_sight_corner_test() {
+        let walls = [(0, 1), (1, 0)];
+        let is_wall = |x: i64, y: i64| walls.contains(&(x, y));
+
+        assert!(! line_of_sight(&is_wall, (0, 0), (1, 1)));
+        assert!(line_of_sight(&is_wall, (1, 1), (3, 2)));
+        assert!(! line_of_sight(&is_wall, (0, 0), (0, 2)));
+    }
+
+    #[test]
+    fn theta_star_resolver_waypoints_test() {
+        let matrix = vec![
+            vec![1, 0, 0, 0, 0],
+            vec![-1, -1, -1, 0, 0],
+            vec![0, 0, 0, 0, 0],
+            vec![0, 0, 0, 0, 0],
+            vec![2, 0, 0, 0, 0]
+        ];
+        let (fs, aps) = fs_aps_from_matrix(matrix.clone()).unwrap();
+        let start_end = (
+            Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(1) },
+            Field { coordinates: Point { x: Some(4), y: Some(0) }, value: Some(2) }
+        );
+
+        let waypoints = theta_star_resolver(fs, aps, 5, start_end).unwrap();
+
+        assert_eq!(waypoints.first(), Some(&start_end.0.coordinates));
+        assert_eq!(waypoints.last(), Some(&start_end.1.coordinates));
+        assert!(waypoints.len() < 9);
+
+        for waypoint_segment in waypoints.points.windows(2) {
+            assert!(has_line_of_sight(&matrix, waypoint_segment[0], waypoint_segment[1]));
+        }
+    }
+}
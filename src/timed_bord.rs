@@ -0,0 +1,370 @@
+//! # Description
+//! This is the timed bord module, for the levels with blinking walls and patrolling guards.
+//! A `TimedBord` tells which fields are blocked at each timestep,
+//! and the `timed_a_star_resolver` function finds the road which arrives first on the end,
+//! waiting on a field when it's needed.
+
+pub use crate::graph::{Field, Point};
+pub use crate::path::Move;
+pub use crate::stats::SearchStats;
+use crate::space_time::{space_time_a_star, SpaceTimeQuery};
+use crate::stats::{record_road_search, RoadCost};
+
+/// When a field is blocked.
+/// `Periodic` blocks the field when `time % period` is one of the `blocked_phases`, like a blinking wall.
+/// `Explicit` blocks the field at each of the `blocked_times`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldSchedule {
+    Periodic { period: usize, blocked_phases: Vec<usize> },
+    Explicit { blocked_times: Vec<usize> }
+}
+
+impl FieldSchedule {
+    pub fn is_blocked_at(&self, time: usize) -> bool {
+        return match self {
+            FieldSchedule::Periodic { period, blocked_phases } => blocked_phases.contains(&(time % period)),
+            FieldSchedule::Explicit { blocked_times } => blocked_times.contains(&time)
+        };
+    }
+}
+
+/// A field of a road with the timestep where the bot is on it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimedPoint {
+    pub point: Point,
+    pub time: usize
+}
+
+/// The cost of a timed road is his number of timesteps, the waits included.
+impl RoadCost for Vec<TimedPoint> {
+    fn road_cost(&self) -> Option<usize> {
+        return self.last().map(|timed_point| timed_point.time);
+    }
+}
+
+/// The obstacles of a bord which change with the time.
+/// The walls of the matrix are always blocked, a `TimedBord` only adds the fields blocked at some timesteps.
+/// A patrol is a guard which is on the point `time % points.len()` of his round at each timestep
+/// and the bot cannot swap his field with a guard.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::Point;
+/// use esgi_arena_resolver_algorithms::timed_bord::{ TimedBord, FieldSchedule };
+///
+/// let mut timed_bord = TimedBord::new(3);
+///
+/// timed_bord.add_schedule(Point { x: Some(0), y: Some(1) }, FieldSchedule::Periodic { period: 2, blocked_phases: vec![0] }).unwrap();
+/// timed_bord.add_patrol(vec![Point { x: Some(2), y: Some(0) }, Point { x: Some(2), y: Some(1) }]).unwrap();
+///
+/// assert!(timed_bord.is_blocked_at(Point { x: Some(0), y: Some(1) }, 4));
+/// assert!(! timed_bord.is_blocked_at(Point { x: Some(0), y: Some(1) }, 5));
+/// assert!(timed_bord.is_blocked_at(Point { x: Some(2), y: Some(1) }, 3));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TimedBord {
+    matrix_size: usize,
+    schedules: Vec<(usize, FieldSchedule)>,
+    patrols: Vec<Vec<usize>>
+}
+
+impl TimedBord {
+    pub fn new(matrix_size: usize) -> Self {
+        return Self {
+            matrix_size,
+            schedules: Vec::new(),
+            patrols: Vec::new()
+        };
+    }
+
+    pub fn add_schedule(&mut self, point: Point, schedule: FieldSchedule) -> Result<(), &'static str> {
+        if let FieldSchedule::Periodic { period: 0, .. } = schedule {
+            return Err("The parameters MUST be initializes");
+        }
+
+        self.schedules.push((point.get_index(self.matrix_size)?, schedule));
+
+        return Ok(());
+    }
+
+    /// Add a guard which follows the `patrol_points` in a loop, one point for each timestep.
+    /// Each point MUST be a neighbour of the previous one or the same point, the last point included.
+    pub fn add_patrol(&mut self, patrol_points: Vec<Point>) -> Result<(), &'static str> {
+        if patrol_points.is_empty() {
+            return Err("The parameters MUST be initializes");
+        }
+
+        for (index, point) in patrol_points.iter().enumerate() {
+            let next_point = patrol_points[(index + 1) % patrol_points.len()];
+
+            if *point != next_point {
+                Move::between(*point, next_point)?;
+            }
+        }
+
+        self.patrols.push(patrol_points.iter().map(|point| point.get_index(self.matrix_size)).collect::<Result<Vec<usize>, &'static str>>()?);
+
+        return Ok(());
+    }
+
+    pub fn is_blocked_at(&self, point: Point, time: usize) -> bool {
+        return point.get_index(self.matrix_size).is_ok_and(|index| self.is_index_blocked_at(index, time));
+    }
+
+    fn is_index_blocked_at(&self, index: usize, time: usize) -> bool {
+        return self.schedules.iter().any(|(field_index, schedule)| *field_index == index && schedule.is_blocked_at(time))
+            || self.patrols.iter().any(|patrol| patrol[time % patrol.len()] == index);
+    }
+
+    /// Check that the bot can go from `from_index` to `to_index` and arrive at `time`.
+    fn is_allowed_move(&self, from_index: usize, to_index: usize, time: usize) -> bool {
+        if self.is_index_blocked_at(to_index, time) {
+            return false;
+        }
+
+        return from_index == to_index || ! self.patrols.iter().any(|patrol| {
+            return patrol[(time - 1) % patrol.len()] == to_index && patrol[time % patrol.len()] == from_index;
+        });
+    }
+
+    /// Get the timestep from which the blocked fields come back with the same period, and this period.
+    /// After this timestep, waiting one more period on a field never helps to find a shorter road.
+    fn get_periodic_horizon(&self) -> Result<(usize, usize), &'static str> {
+        let mut first_periodic_time = 0;
+        let mut period: usize = 1;
+
+        for (_, schedule) in self.schedules.iter() {
+            match schedule {
+                FieldSchedule::Periodic { period: schedule_period, .. } => period = get_least_common_multiple(period, *schedule_period)?,
+                FieldSchedule::Explicit { blocked_times } => {
+                    if let Some(last_blocked_time) = blocked_times.iter().max() {
+                        let schedule_periodic_time = last_blocked_time.checked_add(1).ok_or("The search budget is exhausted before finding a road")?;
+
+                        first_periodic_time = first_periodic_time.max(schedule_periodic_time);
+                    }
+                }
+            }
+        }
+
+        for patrol in self.patrols.iter() {
+            period = get_least_common_multiple(period, patrol.len())?;
+        }
+
+        return Ok((first_periodic_time, period));
+    }
+}
+
+fn get_least_common_multiple(first_number: usize, second_number: usize) -> Result<usize, &'static str> {
+    let (mut first_divisor, mut second_divisor) = (first_number, second_number);
+
+    while second_divisor != 0 {
+        (first_divisor, second_divisor) = (second_divisor, first_divisor % second_divisor);
+    }
+
+    return (first_number / first_divisor).checked_mul(second_number).ok_or("The search budget is exhausted before finding a road");
+}
+
+/// Timed A* resolver function.
+/// It's find the road which arrives first on the end of a bord where some fields are blocked at some timesteps.
+/// The bot starts at the timestep 0 and can wait on a field while it's not blocked.
+/// Each point of the road has his timestep, a wait gives the same point with the next timestep.
+/// After the last explicit blocked timestep, a field is only tried once for each phase of the period of the bord,
+/// so a level without road stops after every field has been tried at every phase.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::timed_bord::{ timed_a_star_resolver, TimedBord, FieldSchedule, TimedPoint };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![1, 0, 2],
+///     vec![-1, -1, -1],
+///     vec![-1, -1, -1]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(0), y: Some(2) },
+///         value: Some(2)
+///     }
+/// );
+/// let mut timed_bord = TimedBord::new(matrix_example.len());
+///
+/// timed_bord.add_schedule(Point { x: Some(0), y: Some(1) }, FieldSchedule::Periodic { period: 3, blocked_phases: vec![0, 1] }).unwrap();
+///
+/// let timed_road = timed_a_star_resolver(fs_example, aps_example, matrix_example.len(), start_end_fields, &timed_bord).unwrap();
+///
+/// assert_eq!(timed_road, vec![
+///     TimedPoint { point: Point { x: Some(0), y: Some(0) }, time: 0 },
+///     TimedPoint { point: Point { x: Some(0), y: Some(0) }, time: 1 },
+///     TimedPoint { point: Point { x: Some(0), y: Some(1) }, time: 2 },
+///     TimedPoint { point: Point { x: Some(0), y: Some(2) }, time: 3 }
+/// ]);
+/// ```
+pub fn timed_a_star_resolver(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), timed_bord: &TimedBord) -> Result<Vec<TimedPoint>, &'static str> {
+    return timed_a_star_resolver_with_stats(fs, aps, matrix_size, start_end_point, timed_bord, &mut SearchStats::new());
+}
+
+/// Timed A* resolver function which fill the search statistics.
+/// The expanded and generated fields are the states of the search, a field at a timestep.
+/// The path cost is the number of timesteps of the road, the waits included.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::timed_bord::{ timed_a_star_resolver_with_stats, TimedBord, FieldSchedule, SearchStats };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![1, 0, 2],
+///     vec![-1, -1, -1],
+///     vec![-1, -1, -1]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(0), y: Some(2) },
+///         value: Some(2)
+///     }
+/// );
+/// let mut timed_bord = TimedBord::new(matrix_example.len());
+/// let mut stats = SearchStats::new();
+///
+/// timed_bord.add_schedule(Point { x: Some(0), y: Some(1) }, FieldSchedule::Periodic { period: 3, blocked_phases: vec![0, 1] }).unwrap();
+///
+/// timed_a_star_resolver_with_stats(fs_example, aps_example, matrix_example.len(), start_end_fields, &timed_bord, &mut stats).unwrap();
+///
+/// assert_eq!(stats.path_cost, Some(3));
+/// assert!(stats.nodes_expanded >= 4);
+/// ```
+pub fn timed_a_star_resolver_with_stats(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), timed_bord: &TimedBord, stats: &mut SearchStats) -> Result<Vec<TimedPoint>, &'static str> {
+    return record_road_search(stats, |stats| timed_a_star_search(fs, aps, matrix_size, start_end_point, timed_bord, stats));
+}
+
+fn timed_a_star_search(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), timed_bord: &TimedBord, stats: &mut SearchStats) -> Result<Vec<TimedPoint>, &'static str> {
+    if fs.is_empty() || aps.is_empty() || start_end_point == (Field::new(), Field::new()) || timed_bord.matrix_size != matrix_size {
+        return Err("The parameters MUST be initializes");
+    }
+
+    let (start_point, end_point) = start_end_point;
+    let fields_number = aps.len() - 1;
+    let start_index = start_point.coordinates.get_index(matrix_size)?;
+    let end_index = end_point.coordinates.get_index(matrix_size)?;
+
+    if start_index >= fields_number || end_index >= fields_number {
+        return Err("The target point cannot be found inside the matrix");
+    }
+
+    // A road which arrives first never comes back on a field at the same phase of the period,
+    // so it cannot be longer than one period for each field after the first periodic time.
+    let (first_periodic_time, period) = timed_bord.get_periodic_horizon()?;
+    let max_time = period.checked_mul(fields_number)
+        .and_then(|periodic_time| periodic_time.checked_add(first_periodic_time))
+        .ok_or("The search budget is exhausted before finding a road")?;
+    let query = SpaceTimeQuery {
+        start_index,
+        start_time: 0,
+        end_index,
+        goal_min_time: 0,
+        max_time,
+        window_end: None,
+        periodic_times: Some((first_periodic_time, period))
+    };
+    let is_allowed_move = |from_index: usize, to_index: usize, time: usize| timed_bord.is_allowed_move(from_index, to_index, time);
+
+    let road = space_time_a_star(&fs, &aps, matrix_size, &query, &is_allowed_move, stats)?.ok_or("It seem that it has no end to this level")?;
+    let mut timed_road: Vec<TimedPoint> = Vec::new();
+
+    for (time, index) in road.into_iter().enumerate() {
+        timed_road.push(TimedPoint { point: Point::from_index(index, matrix_size)?, time });
+    }
+
+    return Ok(timed_road);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::fs_aps_from_matrix;
+
+    #[test]
+    fn get_periodic_horizon_test() {
+        let mut timed_bord = TimedBord::new(3);
+
+        assert_eq!(timed_bord.get_periodic_horizon(), Ok((0, 1)));
+
+        timed_bord.add_schedule(Point { x: Some(0), y: Some(0) }, FieldSchedule::Periodic { period: 4, blocked_phases: vec![1] }).unwrap();
+        timed_bord.add_schedule(Point { x: Some(0), y: Some(1) }, FieldSchedule::Explicit { blocked_times: vec![2, 7] }).unwrap();
+        timed_bord.add_patrol(vec![Point { x: Some(1), y: Some(0) }, Point { x: Some(1), y: Some(1) }, Point { x: Some(1), y: Some(1) }, Point { x: Some(1), y: Some(0) }, Point { x: Some(1), y: Some(0) }, Point { x: Some(1), y: Some(0) }]).unwrap();
+
+        assert_eq!(timed_bord.get_periodic_horizon(), Ok((8, 12)));
+        assert!(timed_bord.add_patrol(vec![Point { x: Some(1), y: Some(0) }, Point { x: Some(1), y: Some(2) }]).is_err());
+        assert!(timed_bord.add_schedule(Point { x: Some(0), y: Some(0) }, FieldSchedule::Periodic { period: 0, blocked_phases: vec![] }).is_err());
+
+        timed_bord.add_schedule(Point { x: Some(2), y: Some(2) }, FieldSchedule::Explicit { blocked_times: vec![usize::MAX] }).unwrap();
+
+        assert_eq!(timed_bord.get_periodic_horizon(), Err("The search budget is exhausted before finding a road"));
+    }
+
+    #[test]
+    fn timed_a_star_resolver_patrol_swap_test() {
+        let matrix = vec![
+            vec![1, 0, 0, 2],
+            vec![-1, -1, -1, -1],
+            vec![-1, -1, -1, -1],
+            vec![-1, -1, -1, -1]
+        ];
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let start_end = (
+            Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(1) },
+            Field { coordinates: Point { x: Some(0), y: Some(3) }, value: Some(2) }
+        );
+        let mut timed_bord = TimedBord::new(4);
+
+        timed_bord.add_patrol(vec![Point { x: Some(0), y: Some(2) }, Point { x: Some(0), y: Some(1) }, Point { x: Some(0), y: Some(2) }, Point { x: Some(0), y: Some(3) }]).unwrap();
+
+        assert!(timed_a_star_resolver(fs.clone(), aps.clone(), 4, start_end, &timed_bord).is_err());
+
+        let mut timed_bord = TimedBord::new(4);
+
+        timed_bord.add_schedule(Point { x: Some(0), y: Some(2) }, FieldSchedule::Explicit { blocked_times: vec![2, 3] }).unwrap();
+
+        let timed_road = timed_a_star_resolver(fs, aps, 4, start_end, &timed_bord).unwrap();
+
+        assert_eq!(timed_road.len(), 6);
+        assert_eq!(timed_road.iter().map(|timed_point| timed_point.time).collect::<Vec<usize>>(), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(timed_road[4].point, Point { x: Some(0), y: Some(2) });
+    }
+
+    #[test]
+    fn timed_a_star_resolver_periodic_no_road_test() {
+        let mut matrix = vec![vec![0; 10]; 10];
+
+        matrix[9][0] = 1;
+        matrix[0][9] = 2;
+        matrix[1][9] = -1;
+
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let start_end = (
+            Field { coordinates: Point { x: Some(9), y: Some(0) }, value: Some(1) },
+            Field { coordinates: Point { x: Some(0), y: Some(9) }, value: Some(2) }
+        );
+        let mut timed_bord = TimedBord::new(10);
+
+        timed_bord.add_schedule(Point { x: Some(0), y: Some(8) }, FieldSchedule::Periodic { period: 2, blocked_phases: vec![1] }).unwrap();
+        timed_bord.add_schedule(Point { x: Some(0), y: Some(9) }, FieldSchedule::Periodic { period: 2, blocked_phases: vec![1] }).unwrap();
+        timed_bord.add_schedule(Point { x: Some(5), y: Some(5) }, FieldSchedule::Periodic { period: 5, blocked_phases: vec![0] }).unwrap();
+        timed_bord.add_schedule(Point { x: Some(6), y: Some(6) }, FieldSchedule::Periodic { period: 7, blocked_phases: vec![0] }).unwrap();
+
+        assert_eq!(timed_bord.get_periodic_horizon(), Ok((0, 70)));
+        assert_eq!(timed_a_star_resolver(fs, aps, 10, start_end, &timed_bord), Err("It seem that it has no end to this level"));
+    }
+}
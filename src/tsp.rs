@@ -0,0 +1,300 @@
+//! # Description
+//! This is the travelling salesman handling module.
+//! It's find the shorter road from the start which visit all the end points of a bord.
+//! The distances between the start and the end points are computed with a BFS from each of them.
+//! The visiting order is exact for a small number of end points (Held-Karp dynamic programming)
+//! and approximated with a nearest neighbour order improved by 2-opt moves for a bigger number.
+//! [For more explainations](https://en.wikipedia.org/wiki/Travelling_salesman_problem)
+
+pub use crate::graph::{Field, Point};
+pub use crate::path::Path;
+pub use crate::stats::SearchStats;
+use crate::bfs::{bfs_search_with_stats, get_index_road_from_parents};
+use crate::stats::record_road_search;
+
+/// The maximum number of end points for which the exact visiting order is computed.
+pub const EXACT_TOUR_MAX_END_POINTS: usize = 12;
+
+/// Get the shorter road from the start which visit all the end points.
+/// The returned road is the concatenation of the roads between each visited point.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::tsp::tsp_resolver;
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![2, 0, 0],
+///     vec![-1, -1, 0],
+///     vec![1, 0, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(2), y: Some(0) },
+///         value: Some(1)
+///     },
+///     vec![Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(2)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(2), y: Some(2) },
+///         value: Some(2)
+///     }]
+/// );
+///
+/// assert_eq!(tsp_resolver(fs_example, aps_example, matrix_example.len(), start_end_fields).unwrap().points, vec![
+///     Point { x: Some(2), y: Some(0) },
+///     Point { x: Some(2), y: Some(1) },
+///     Point { x: Some(2), y: Some(2) },
+///     Point { x: Some(1), y: Some(2) },
+///     Point { x: Some(0), y: Some(2) },
+///     Point { x: Some(0), y: Some(1) },
+///     Point { x: Some(0), y: Some(0) }
+/// ]);
+/// ```
+pub fn tsp_resolver(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Vec<Field>)) -> Result<Path, &'static str> {
+    return tsp_resolver_with_stats(fs, aps, matrix_size, start_end_point, &mut SearchStats::new());
+}
+
+/// Travelling salesman resolver function which fill the search statistics.
+/// The statistics are the sum of the BFS done from the start and from each end point,
+/// the open list size is the bigger one of these BFS.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::tsp::{ tsp_resolver_with_stats, SearchStats };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![2, 0, 0],
+///     vec![-1, -1, 0],
+///     vec![1, 0, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(2), y: Some(0) },
+///         value: Some(1)
+///     },
+///     vec![Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(2)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(2), y: Some(2) },
+///         value: Some(2)
+///     }]
+/// );
+/// let mut stats = SearchStats::new();
+///
+/// tsp_resolver_with_stats(fs_example, aps_example, matrix_example.len(), start_end_fields, &mut stats).unwrap();
+///
+/// assert_eq!(stats.path_cost, Some(6));
+/// assert_eq!(stats.nodes_expanded, 3 * 7);
+/// assert_eq!(stats.reopened_nodes, 0);
+/// ```
+pub fn tsp_resolver_with_stats(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Vec<Field>), stats: &mut SearchStats) -> Result<Path, &'static str> {
+    return record_road_search(stats, |stats| tsp_search(fs, aps, matrix_size, start_end_point, stats));
+}
+
+fn tsp_search(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Vec<Field>), stats: &mut SearchStats) -> Result<Path, &'static str> {
+    if fs.is_empty() || aps.is_empty() || start_end_point.0 == Field::new() || start_end_point.1.is_empty() {
+        return Err("The parameters MUST be initializes");
+    }
+
+    let (start_point, end_points) = start_end_point;
+    let fields_number = aps.len() - 1;
+    let mut tour_indexes: Vec<usize> = vec![start_point.coordinates.get_index(matrix_size)?];
+
+    for end_point in end_points {
+        let end_index = end_point.coordinates.get_index(matrix_size)?;
+
+        if ! tour_indexes.contains(&end_index) {
+            tour_indexes.push(end_index);
+        }
+    }
+
+    if tour_indexes.iter().any(|index| *index >= fields_number) {
+        return Err("The target point cannot be found inside the matrix");
+    }
+
+    let mut distances: Vec<Vec<usize>> = Vec::new();
+    let mut parents: Vec<Vec<Option<usize>>> = Vec::new();
+
+    for from_index in tour_indexes.iter() {
+        let (from_distances, from_parents) = bfs_search_with_stats(&fs, &aps, matrix_size, *from_index, None, &|_, _| true, stats)?;
+        let mut tour_distances: Vec<usize> = Vec::new();
+
+        for to_index in tour_indexes.iter() {
+            tour_distances.push(from_distances[*to_index].ok_or("It seem that it has no end to this level")?);
+        }
+
+        distances.push(tour_distances);
+        parents.push(from_parents);
+    }
+
+    let visiting_order = if tour_indexes.len() - 1 <= EXACT_TOUR_MAX_END_POINTS {
+        get_exact_visiting_order(&distances)
+    } else {
+        get_two_opt_visiting_order(&distances)
+    };
+
+    let mut start_to_end_road: Vec<Point> = vec![Point::from_index(tour_indexes[0], matrix_size)?];
+    let mut current_point = 0;
+
+    for next_point in visiting_order {
+        let road = get_index_road_from_parents(&parents[current_point], matrix_size, tour_indexes[next_point])?;
+        start_to_end_road.extend(road.into_iter().skip(1));
+        current_point = next_point;
+    }
+
+    return Ok(Path::new(start_to_end_road));
+}
+
+/// Held-Karp dynamic programming over the subsets of end points.
+/// The point 0 is the start and the road does not come back to the start.
+fn get_exact_visiting_order(distances: &[Vec<usize>]) -> Vec<usize> {
+    let end_points_number = distances.len() - 1;
+    let subsets_number = 1 << end_points_number;
+    let mut costs: Vec<Vec<Option<usize>>> = vec![vec![None; end_points_number]; subsets_number];
+    let mut previous_points: Vec<Vec<Option<usize>>> = vec![vec![None; end_points_number]; subsets_number];
+
+    for last_point in 0..end_points_number {
+        costs[1 << last_point][last_point] = Some(distances[0][last_point + 1]);
+    }
+
+    for subset in 1..subsets_number {
+        for last_point in 0..end_points_number {
+            let subset_cost = match costs[subset][last_point] {
+                Some(subset_cost) => subset_cost,
+                None => continue
+            };
+
+            for next_point in 0..end_points_number {
+                if subset & (1 << next_point) != 0 {
+                    continue;
+                }
+
+                let next_subset = subset | (1 << next_point);
+                let next_cost = subset_cost + distances[last_point + 1][next_point + 1];
+
+                if costs[next_subset][next_point].is_none_or(|cost| next_cost < cost) {
+                    costs[next_subset][next_point] = Some(next_cost);
+                    previous_points[next_subset][next_point] = Some(last_point);
+                }
+            }
+        }
+    }
+
+    let full_subset = subsets_number - 1;
+    let mut last_point = (0..end_points_number)
+        .min_by_key(|last_point| costs[full_subset][*last_point].unwrap_or(usize::MAX))
+        .unwrap_or(0);
+    let mut subset = full_subset;
+    let mut visiting_order: Vec<usize> = Vec::new();
+
+    while subset != 0 {
+        visiting_order.push(last_point + 1);
+
+        let previous_point = previous_points[subset][last_point];
+        subset &= ! (1 << last_point);
+
+        if let Some(previous_point) = previous_point {
+            last_point = previous_point;
+        }
+    }
+
+    visiting_order.reverse();
+
+    return visiting_order;
+}
+
+/// Nearest neighbour visiting order improved by reversing parts of the order (2-opt moves)
+/// until no reverse make the road shorter.
+fn get_two_opt_visiting_order(distances: &[Vec<usize>]) -> Vec<usize> {
+    let mut visiting_order: Vec<usize> = Vec::new();
+    let mut remaining_points: Vec<usize> = (1..distances.len()).collect();
+    let mut current_point = 0;
+
+    while ! remaining_points.is_empty() {
+        let nearest_position = (0..remaining_points.len())
+            .min_by_key(|position| distances[current_point][remaining_points[*position]])
+            .unwrap();
+
+        current_point = remaining_points.remove(nearest_position);
+        visiting_order.push(current_point);
+    }
+
+    let mut is_improved = true;
+
+    while is_improved {
+        is_improved = false;
+
+        for first in 0..visiting_order.len() {
+            for last in first + 1..visiting_order.len() {
+                let before_point = if first == 0 { 0 } else { visiting_order[first - 1] };
+                let after_point = visiting_order.get(last + 1).cloned();
+
+                let current_cost = distances[before_point][visiting_order[first]]
+                    + after_point.map_or(0, |after_point| distances[visiting_order[last]][after_point]);
+                let reversed_cost = distances[before_point][visiting_order[last]]
+                    + after_point.map_or(0, |after_point| distances[visiting_order[first]][after_point]);
+
+                if reversed_cost < current_cost {
+                    visiting_order[first..=last].reverse();
+                    is_improved = true;
+                }
+            }
+        }
+    }
+
+    return visiting_order;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Get the cost of a visiting order which start from the point 0.
+    fn get_visiting_order_cost(distances: &[Vec<usize>], visiting_order: &[usize]) -> usize {
+        let mut cost = 0;
+        let mut current_point = 0;
+
+        for next_point in visiting_order {
+            cost += distances[current_point][*next_point];
+            current_point = *next_point;
+        }
+
+        return cost;
+    }
+
+    fn line_distances(positions: &[usize]) -> Vec<Vec<usize>> {
+        return positions.iter()
+            .map(|from| positions.iter().map(|to| (*from as i64 - *to as i64).unsigned_abs() as usize).collect())
+            .collect();
+    }
+
+    #[test]
+    fn get_exact_visiting_order_test() {
+        let distances = line_distances(&[5, 9, 0, 7, 3]);
+        let visiting_order = get_exact_visiting_order(&distances);
+
+        assert_eq!(visiting_order, vec![3, 1, 4, 2]);
+        assert_eq!(get_visiting_order_cost(&distances, &visiting_order), 13);
+    }
+
+    #[test]
+    fn get_two_opt_visiting_order_test() {
+        let distances = line_distances(&[5, 9, 0, 7, 3, 1, 8]);
+        let exact_visiting_order = get_exact_visiting_order(&distances);
+        let visiting_order = get_two_opt_visiting_order(&distances);
+
+        let mut visited_points = visiting_order.clone();
+        visited_points.sort();
+
+        assert_eq!(visited_points, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(get_visiting_order_cost(&distances, &visiting_order), get_visiting_order_cost(&distances, &exact_visiting_order));
+    }
+}
@@ -0,0 +1,319 @@
+//! # Description
+//! This is the direction aware A* module.
+//! The state of the search is a field with the heading used to enter it,
+//! so the cost of a move can include a penalty when the heading changes.
+//! It's give smoother roads for the bots which pay extra to turn.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+pub use crate::graph::{Field, Point};
+pub use crate::path::{Path, Move, Turn};
+pub use crate::stats::SearchStats;
+use crate::graph::get_element_childs_slice;
+use crate::cost::Cost;
+use crate::stats::record_road_search;
+
+/// Number of headings of a state: the four moves and no heading for the start.
+const HEADINGS_NUMBER: usize = 5;
+
+/// The costs used by the `a_star_turn_penalty_resolver` function.
+/// `move_cost` is the cost of each move, `quarter_turn_cost` is added for a 90° turn
+/// and `half_turn_cost` is added for a 180° turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TurnPenalties {
+    pub move_cost: u32,
+    pub quarter_turn_cost: u32,
+    pub half_turn_cost: u32
+}
+
+impl TurnPenalties {
+    pub fn new() -> Self {
+        return Self {
+            move_cost: 1,
+            quarter_turn_cost: 0,
+            half_turn_cost: 0
+        };
+    }
+
+    /// Get the cost of a move with the heading before the move.
+    /// It's return a error when the cost cannot be represented by a `u32`.
+    pub fn move_cost(&self, heading: Option<Move>, next_move: Move) -> Result<u32, &'static str> {
+        let turn_cost = match heading.map(|heading| heading.turn_to(next_move)) {
+            Some(Turn::Left) | Some(Turn::Right) => self.quarter_turn_cost,
+            Some(Turn::Back) => self.half_turn_cost,
+            Some(Turn::Straight) | None => 0
+        };
+
+        return self.move_cost.checked_add(turn_cost).ok_or("The road cost is too big");
+    }
+}
+
+impl Default for TurnPenalties {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+fn get_heading_index(heading: Option<Move>) -> usize {
+    return match heading {
+        Some(Move::Up) => 0,
+        Some(Move::Down) => 1,
+        Some(Move::Left) => 2,
+        Some(Move::Right) => 3,
+        None => 4
+    };
+}
+
+fn get_heading(heading_index: usize) -> Option<Move> {
+    return match heading_index {
+        0 => Some(Move::Up),
+        1 => Some(Move::Down),
+        2 => Some(Move::Left),
+        3 => Some(Move::Right),
+        _ => None
+    };
+}
+
+/// Direction aware A* resolver function.
+/// It's find the cheaper road between two points of a graph when turning has a cost.
+/// `initial_heading` is the heading of the bot on the start field, `None` when the first move is free.
+/// The returned path contains the same points as a `a_star_resolver` path and his cost includes the turn penalties.
+/// It's return a error when a cost of the search cannot be represented by a `u32`.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::turn_penalty::{ a_star_turn_penalty_resolver, TurnPenalties, Move, Turn };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![1, 0, 0],
+///     vec![0, 0, 0],
+///     vec![0, 0, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(2), y: Some(2) },
+///         value: Some(2)
+///     }
+/// );
+/// let penalties = TurnPenalties { move_cost: 1, quarter_turn_cost: 5, half_turn_cost: 10 };
+///
+/// let path = a_star_turn_penalty_resolver(fs_example, aps_example, matrix_example.len(), start_end_fields, penalties, Some(Move::Down)).unwrap();
+///
+/// assert_eq!(path.cost, 9);
+/// assert_eq!(path.moves().unwrap(), vec![Move::Down, Move::Down, Move::Right, Move::Right]);
+/// ```
+pub fn a_star_turn_penalty_resolver(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), penalties: TurnPenalties, initial_heading: Option<Move>) -> Result<Path, &'static str> {
+    return a_star_turn_penalty_resolver_with_stats(fs, aps, matrix_size, start_end_point, penalties, initial_heading, &mut SearchStats::new());
+}
+
+/// Direction aware A* resolver function which fill the search statistics.
+/// The expanded and generated fields are the states of the search, a field with a heading,
+/// so a field can be expanded once for each heading. The path cost includes the turn penalties.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::turn_penalty::{ a_star_turn_penalty_resolver_with_stats, TurnPenalties, Move, SearchStats };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![1, 0, 0],
+///     vec![0, 0, 0],
+///     vec![0, 0, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(2), y: Some(2) },
+///         value: Some(2)
+///     }
+/// );
+/// let penalties = TurnPenalties { move_cost: 1, quarter_turn_cost: 5, half_turn_cost: 10 };
+/// let mut stats = SearchStats::new();
+///
+/// a_star_turn_penalty_resolver_with_stats(fs_example, aps_example, matrix_example.len(), start_end_fields, penalties, Some(Move::Down), &mut stats).unwrap();
+///
+/// assert_eq!(stats.path_cost, Some(9));
+/// assert!(stats.nodes_expanded >= 5);
+/// ```
+pub fn a_star_turn_penalty_resolver_with_stats(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), penalties: TurnPenalties, initial_heading: Option<Move>, stats: &mut SearchStats) -> Result<Path, &'static str> {
+    return record_road_search(stats, |stats| a_star_turn_penalty_search(fs, aps, matrix_size, start_end_point, penalties, initial_heading, stats));
+}
+
+fn a_star_turn_penalty_search(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), penalties: TurnPenalties, initial_heading: Option<Move>, stats: &mut SearchStats) -> Result<Path, &'static str> {
+    if fs.is_empty() || aps.is_empty() || start_end_point == (Field::new(), Field::new()) || penalties.move_cost == 0 {
+        return Err("The parameters MUST be initializes");
+    }
+
+    let (start_point, end_point) = start_end_point;
+    let fields_number = aps.len() - 1;
+    let start_index = start_point.coordinates.get_index(matrix_size)?;
+    let end_index = end_point.coordinates.get_index(matrix_size)?;
+
+    if start_index >= fields_number || end_index >= fields_number {
+        return Err("The target point cannot be found inside the matrix");
+    }
+
+    let heuristic = |index: usize| -> Result<u32, &'static str> {
+        let (x, y) = (index / matrix_size, index % matrix_size);
+        let (end_x, end_y) = (end_index / matrix_size, end_index % matrix_size);
+
+        return penalties.move_cost.checked_mul_distance(x.abs_diff(end_x) + y.abs_diff(end_y)).ok_or("The road cost is too big");
+    };
+    let states_number = fields_number * HEADINGS_NUMBER;
+    let mut costs: Vec<Option<u32>> = vec![None; states_number];
+    let mut parents: Vec<Option<usize>> = vec![None; states_number];
+    let mut closed: Vec<bool> = vec![false; states_number];
+    let mut open_list: BinaryHeap<Reverse<(u32, usize)>> = BinaryHeap::new();
+
+    let start_state = start_index * HEADINGS_NUMBER + get_heading_index(initial_heading);
+    costs[start_state] = Some(0);
+    open_list.push(Reverse((heuristic(start_index)?, start_state)));
+    stats.update_open_list_size(open_list.len());
+
+    while let Some(Reverse((_, current_state))) = open_list.pop() {
+        if closed[current_state] {
+            continue;
+        }
+
+        closed[current_state] = true;
+        stats.nodes_expanded += 1;
+
+        let current_index = current_state / HEADINGS_NUMBER;
+        let current_heading = get_heading(current_state % HEADINGS_NUMBER);
+        let current_cost = costs[current_state].unwrap();
+
+        if current_index == end_index {
+            let mut points: Vec<Point> = Vec::new();
+            let mut state = Some(current_state);
+
+            while let Some(road_state) = state {
+                points.push(Point::from_index(road_state / HEADINGS_NUMBER, matrix_size)?);
+                state = parents[road_state];
+            }
+
+            points.reverse();
+
+            return Ok(Path::with_cost(points, current_cost as usize));
+        }
+
+        let current_point = Point::from_index(current_index, matrix_size)?;
+
+        for child in get_element_childs_slice(&fs, &aps, current_index)? {
+            if child.value == Some(-1) {
+                continue;
+            }
+
+            let child_index = child.coordinates.get_index(matrix_size)?;
+            let child_move = match Move::between(current_point, child.coordinates) {
+                Ok(child_move) => child_move,
+                Err(_) => continue
+            };
+
+            if child_index >= fields_number {
+                continue;
+            }
+
+            let child_state = child_index * HEADINGS_NUMBER + get_heading_index(Some(child_move));
+            let child_cost = current_cost.checked_add(penalties.move_cost(current_heading, child_move)?).ok_or("The road cost is too big")?;
+
+            if closed[child_state] || costs[child_state].is_some_and(|cost| cost <= child_cost) {
+                continue;
+            }
+
+            if costs[child_state].is_some() {
+                stats.reopened_nodes += 1;
+            }
+
+            costs[child_state] = Some(child_cost);
+            parents[child_state] = Some(current_state);
+            open_list.push(Reverse((child_cost.checked_add(heuristic(child_index)?).ok_or("The road cost is too big")?, child_state)));
+            stats.nodes_generated += 1;
+        }
+
+        stats.update_open_list_size(open_list.len());
+    }
+
+    return Err("It seem that it has no end to this level");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::fs_aps_from_matrix;
+
+    #[test]
+    fn move_cost_test() {
+        let penalties = TurnPenalties { move_cost: 2, quarter_turn_cost: 3, half_turn_cost: 7 };
+
+        assert_eq!(penalties.move_cost(None, Move::Up), Ok(2));
+        assert_eq!(penalties.move_cost(Some(Move::Up), Move::Up), Ok(2));
+        assert_eq!(penalties.move_cost(Some(Move::Up), Move::Right), Ok(5));
+        assert_eq!(penalties.move_cost(Some(Move::Up), Move::Down), Ok(9));
+
+        let big_penalties = TurnPenalties { move_cost: u32::MAX, quarter_turn_cost: 1, half_turn_cost: 0 };
+
+        assert_eq!(big_penalties.move_cost(Some(Move::Up), Move::Down), Ok(u32::MAX));
+        assert_eq!(big_penalties.move_cost(Some(Move::Up), Move::Right), Err("The road cost is too big"));
+    }
+
+    #[test]
+    fn a_star_turn_penalty_resolver_smooth_road_test() {
+        let matrix = vec![
+            vec![1, 0, 0, 0, 0],
+            vec![0, 0, 0, 0, 0],
+            vec![0, 0, -1, 0, 0],
+            vec![0, 0, 0, 0, 0],
+            vec![0, 0, 0, 0, 2]
+        ];
+        let (fs, aps) = fs_aps_from_matrix(matrix.clone()).unwrap();
+        let start_end = (
+            Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(1) },
+            Field { coordinates: Point { x: Some(4), y: Some(4) }, value: Some(2) }
+        );
+        let penalties = TurnPenalties { move_cost: 1, quarter_turn_cost: 4, half_turn_cost: 8 };
+
+        let path = a_star_turn_penalty_resolver(fs.clone(), aps.clone(), 5, start_end, penalties, None).unwrap();
+        let turns_number = path.turns().unwrap().iter().filter(|turn| **turn != Turn::Straight).count();
+
+        assert!(path.is_valid_on(&matrix));
+        assert_eq!(path.len(), 9);
+        assert_eq!(turns_number, 1);
+        assert_eq!(path.cost, 12);
+
+        let free_turns_path = a_star_turn_penalty_resolver(fs, aps, 5, start_end, TurnPenalties::new(), None).unwrap();
+
+        assert_eq!(free_turns_path.cost, 8);
+    }
+
+    #[test]
+    fn a_star_turn_penalty_resolver_cost_overflow_test() {
+        let matrix = vec![
+            vec![1, 0, 0],
+            vec![0, 0, 0],
+            vec![0, 0, 2]
+        ];
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let start_end = (
+            Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(1) },
+            Field { coordinates: Point { x: Some(2), y: Some(2) }, value: Some(2) }
+        );
+        let penalties = TurnPenalties { move_cost: u32::MAX / 2, quarter_turn_cost: u32::MAX / 2, half_turn_cost: 0 };
+
+        assert_eq!(a_star_turn_penalty_resolver(fs.clone(), aps.clone(), 3, start_end, penalties, None), Err("The road cost is too big"));
+
+        let penalties = TurnPenalties { move_cost: u32::MAX / 4, quarter_turn_cost: 0, half_turn_cost: u32::MAX };
+
+        assert_eq!(a_star_turn_penalty_resolver(fs, aps, 3, start_end, penalties, Some(Move::Up)), Err("The road cost is too big"));
+    }
+}
@@ -0,0 +1,256 @@
+//! # Description
+//! This is the Yen's algorithm module.
+//! It's find the k shorter roads without loop between two points of a graph, from the shorter to the longer.
+//! Each new road is built from a previous road where one move is forbidden after a common root,
+//! the rest of the road (the spur road) is found with a BFS.
+//! [For more explainations](https://en.wikipedia.org/wiki/Yen%27s_algorithm)
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+
+pub use crate::graph::{Field, Point};
+pub use crate::path::Path;
+pub use crate::stats::SearchStats;
+use crate::bfs::bfs_search_with_stats;
+use crate::stats::record_road_search;
+
+/// Find the shorter road of indexes from the start to the end index with a BFS.
+/// The forbidden indexes and the forbidden moves cannot be used by the road.
+#[allow(clippy::too_many_arguments)]
+fn get_shorter_index_road(fs: &[Field], aps: &[u32], matrix_size: usize, start_index: usize, end_index: usize, forbidden_indexes: &[bool], forbidden_moves: &HashSet<(usize, usize)>, stats: &mut SearchStats) -> Result<Option<Vec<usize>>, &'static str> {
+    let is_allowed_move = |from_index: usize, to_index: usize| -> bool {
+        return ! forbidden_indexes[to_index] && ! forbidden_moves.contains(&(from_index, to_index));
+    };
+    let (distances, parents) = bfs_search_with_stats(fs, aps, matrix_size, start_index, Some(end_index), &is_allowed_move, stats)?;
+
+    if distances[end_index].is_none() {
+        return Ok(None);
+    }
+
+    let mut index_road: Vec<usize> = vec![end_index];
+    let mut current_index = end_index;
+
+    while let Some(parent_index) = parents[current_index] {
+        index_road.push(parent_index);
+        current_index = parent_index;
+    }
+
+    index_road.reverse();
+
+    return Ok(Some(index_road));
+}
+
+/// Yen's k shorter roads resolver function.
+/// It's return at most `roads_number` distinct roads without loop from the start to the end,
+/// sorted from the shorter to the longer.
+/// The input are the same as the `a_star_resolver` function.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::yen::{ yen_k_shortest_roads_resolver, Path };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![1, 0, 0],
+///     vec![0, -1, 0],
+///     vec![0, 0, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(2), y: Some(2) },
+///         value: Some(2)
+///     }
+/// );
+///
+/// let roads = yen_k_shortest_roads_resolver(fs_example, aps_example, matrix_example.len(), start_end_fields, 5).unwrap();
+///
+/// assert_eq!(roads, vec![
+///     Path::new(vec![
+///         Point { x: Some(0), y: Some(0) },
+///         Point { x: Some(0), y: Some(1) },
+///         Point { x: Some(0), y: Some(2) },
+///         Point { x: Some(1), y: Some(2) },
+///         Point { x: Some(2), y: Some(2) }
+///     ]),
+///     Path::new(vec![
+///         Point { x: Some(0), y: Some(0) },
+///         Point { x: Some(1), y: Some(0) },
+///         Point { x: Some(2), y: Some(0) },
+///         Point { x: Some(2), y: Some(1) },
+///         Point { x: Some(2), y: Some(2) }
+///     ])
+/// ]);
+/// ```
+pub fn yen_k_shortest_roads_resolver(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), roads_number: usize) -> Result<Vec<Path>, &'static str> {
+    return yen_k_shortest_roads_resolver_with_stats(fs, aps, matrix_size, start_end_point, roads_number, &mut SearchStats::new());
+}
+
+/// Yen's k shorter roads resolver function which fill the search statistics.
+/// The statistics are the sum of all the BFS done for the spur roads, and the road cost is the cost of the shorter road.
+///
+/// # Example
+///
+/// ```
+/// use esgi_arena_resolver_algorithms::graph::{ Point, Field, fs_aps_from_matrix };
+/// use esgi_arena_resolver_algorithms::yen::{ yen_k_shortest_roads_resolver_with_stats, SearchStats };
+///
+/// let matrix_example: Vec<Vec<i8>> = vec![
+///     vec![1, 0, 0],
+///     vec![0, -1, 0],
+///     vec![0, 0, 2]
+/// ];
+/// let (fs_example, aps_example) = fs_aps_from_matrix(matrix_example.clone()).unwrap();
+/// let start_end_fields = (Field {
+///         coordinates: Point { x: Some(0), y: Some(0) },
+///         value: Some(1)
+///     },
+///     Field {
+///         coordinates: Point { x: Some(2), y: Some(2) },
+///         value: Some(2)
+///     }
+/// );
+/// let mut stats = SearchStats::new();
+///
+/// let roads = yen_k_shortest_roads_resolver_with_stats(fs_example, aps_example, matrix_example.len(), start_end_fields, 5, &mut stats).unwrap();
+///
+/// assert_eq!(roads.len(), 2);
+/// assert_eq!(stats.path_cost, Some(4));
+/// assert!(stats.nodes_expanded > 8);
+/// ```
+pub fn yen_k_shortest_roads_resolver_with_stats(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), roads_number: usize, stats: &mut SearchStats) -> Result<Vec<Path>, &'static str> {
+    return record_road_search(stats, |stats| yen_search(fs, aps, matrix_size, start_end_point, roads_number, stats));
+}
+
+fn yen_search(fs: Vec<Field>, aps: Vec<u32>, matrix_size: usize, start_end_point: (Field, Field), roads_number: usize, stats: &mut SearchStats) -> Result<Vec<Path>, &'static str> {
+    if fs.is_empty() || aps.is_empty() || start_end_point == (Field::new(), Field::new()) || roads_number == 0 {
+        return Err("The parameters MUST be initializes");
+    }
+
+    let (start_point, end_point) = start_end_point;
+    let fields_number = aps.len() - 1;
+    let start_index = start_point.coordinates.get_index(matrix_size)?;
+    let end_index = end_point.coordinates.get_index(matrix_size)?;
+
+    if start_index >= fields_number || end_index >= fields_number {
+        return Err("The target point cannot be found inside the matrix");
+    }
+
+    let shorter_index_road = get_shorter_index_road(&fs, &aps, matrix_size, start_index, end_index, &vec![false; fields_number], &HashSet::new(), stats)?
+        .ok_or("It seem that it has no end to this level")?;
+
+    let mut index_roads: Vec<Vec<usize>> = vec![shorter_index_road];
+    let mut candidate_roads: BinaryHeap<Reverse<(usize, Vec<usize>)>> = BinaryHeap::new();
+    let mut known_roads: HashSet<Vec<usize>> = index_roads.iter().cloned().collect();
+
+    while index_roads.len() < roads_number {
+        let previous_road = index_roads.last().unwrap().clone();
+
+        for spur_position in 0..previous_road.len() - 1 {
+            let root_road = &previous_road[..=spur_position];
+            let mut forbidden_indexes: Vec<bool> = vec![false; fields_number];
+            let mut forbidden_moves: HashSet<(usize, usize)> = HashSet::new();
+
+            for index_road in index_roads.iter() {
+                if index_road.len() > spur_position + 1 && index_road[..=spur_position] == *root_road {
+                    forbidden_moves.insert((index_road[spur_position], index_road[spur_position + 1]));
+                }
+            }
+
+            for root_index in root_road {
+                forbidden_indexes[*root_index] = true;
+            }
+
+            let spur_road = get_shorter_index_road(&fs, &aps, matrix_size, root_road[spur_position], end_index, &forbidden_indexes, &forbidden_moves, stats)?;
+
+            if let Some(spur_road) = spur_road {
+                let mut candidate_road: Vec<usize> = root_road[..spur_position].to_vec();
+                candidate_road.extend(spur_road);
+
+                if known_roads.insert(candidate_road.clone()) {
+                    candidate_roads.push(Reverse((candidate_road.len(), candidate_road)));
+                }
+            }
+        }
+
+        match candidate_roads.pop() {
+            Some(Reverse((_, candidate_road))) => index_roads.push(candidate_road),
+            None => break
+        }
+    }
+
+    let mut roads: Vec<Path> = Vec::new();
+
+    for index_road in index_roads {
+        let mut road: Vec<Point> = Vec::new();
+
+        for index in index_road {
+            road.push(Point::from_index(index, matrix_size)?);
+        }
+
+        roads.push(Path::new(road));
+    }
+
+    return Ok(roads);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::fs_aps_from_matrix;
+
+    #[test]
+    fn get_shorter_index_road_forbidden_move_test() {
+        let matrix = vec![
+            vec![1, 0, 0],
+            vec![0, -1, 0],
+            vec![0, 0, 2]
+        ];
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let mut forbidden_moves: HashSet<(usize, usize)> = HashSet::new();
+
+        forbidden_moves.insert((0, 1));
+
+        assert_eq!(get_shorter_index_road(&fs, &aps, 3, 0, 8, &[false; 9], &forbidden_moves, &mut SearchStats::new()).unwrap(), Some(vec![0, 3, 6, 7, 8]));
+
+        forbidden_moves.insert((0, 3));
+
+        assert_eq!(get_shorter_index_road(&fs, &aps, 3, 0, 8, &[false; 9], &forbidden_moves, &mut SearchStats::new()).unwrap(), None);
+    }
+
+    #[test]
+    fn yen_k_shortest_roads_resolver_order_test() {
+        let matrix = vec![
+            vec![1, 0, 0, 0],
+            vec![0, 0, -1, 0],
+            vec![0, 0, 0, 0],
+            vec![0, 0, 0, 2]
+        ];
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let start_end = (
+            Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(1) },
+            Field { coordinates: Point { x: Some(3), y: Some(3) }, value: Some(2) }
+        );
+
+        let roads = yen_k_shortest_roads_resolver(fs, aps, 4, start_end, 50).unwrap();
+
+        assert!(roads.len() > 10);
+
+        for road_pair in roads.windows(2) {
+            assert!(road_pair[0].len() <= road_pair[1].len());
+            assert_ne!(road_pair[0], road_pair[1]);
+        }
+
+        for road in roads.iter() {
+            let distinct_points: HashSet<usize> = road.points.iter().map(|point| point.get_index(4).unwrap()).collect();
+
+            assert_eq!(distinct_points.len(), road.len());
+            assert_eq!(road.first(), Some(&start_end.0.coordinates));
+            assert_eq!(road.last(), Some(&start_end.1.coordinates));
+        }
+    }
+}
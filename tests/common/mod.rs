@@ -0,0 +1,35 @@
+//! The random data shared by the test files, a same seed always gives the same data.
+
+use std::any::Any;
+
+/// A xorshift generator.
+pub struct XorShift {
+    state: u64
+}
+
+impl XorShift {
+    pub fn new(seed: u64) -> Self {
+        return Self { state: seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1 };
+    }
+
+    pub fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+
+        return self.state;
+    }
+
+    pub fn below(&mut self, max: usize) -> usize {
+        return (self.next() % max as u64) as usize;
+    }
+}
+
+/// Get the message of a panic caught by `catch_unwind`.
+pub fn get_panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+
+    return payload.downcast_ref::<String>().cloned().unwrap_or_else(|| String::from("a panic without message"));
+}
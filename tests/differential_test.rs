@@ -0,0 +1,399 @@
+#![allow(clippy::needless_return)]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+    use std::panic::{ self, AssertUnwindSafe };
+    use esgi_arena_resolver_algorithms::graph::{ Field, Point, fs_aps_from_matrix };
+    use esgi_arena_resolver_algorithms::a_star::{ a_star_resolver, bidirectional_a_star_resolver, a_star_nearest_road_resolver, a_star_multi_roads_flow_field };
+    use esgi_arena_resolver_algorithms::bfs::bfs_resolver;
+    use esgi_arena_resolver_algorithms::dfs::{ dfs_fs_aps_recursive, Path };
+    use esgi_arena_resolver_algorithms::jps::{ jps_resolver, jps_resolver_with_connectivity, Connectivity };
+    use esgi_arena_resolver_algorithms::ida_star::ida_star_resolver;
+    use esgi_arena_resolver_algorithms::ara_star::{ ara_star_resolver, SearchBudget };
+    use esgi_arena_resolver_algorithms::d_star_lite::DStarLite;
+    use esgi_arena_resolver_algorithms::hpa_star::hpa_star_resolver;
+    use esgi_arena_resolver_algorithms::yen::yen_k_shortest_roads_resolver;
+    use esgi_arena_resolver_algorithms::theta_star::{ theta_star_resolver, has_line_of_sight };
+    use esgi_arena_resolver_algorithms::distance_table::DistanceTable;
+    use esgi_arena_resolver_algorithms::path_validation::get_path_violation;
+    use crate::common::{ XorShift, get_panic_message };
+
+    /// The number of random bords checked by the differential test.
+    const BORDS_NUMBER: u64 = 2000;
+    /// The biggest random bord, the DFS search all the roads so it must stay small.
+    const MAX_BORD_SIZE: usize = 5;
+
+    fn random_bord(seed: u64) -> Vec<Vec<i8>> {
+        let mut rng = XorShift::new(seed);
+        let bord_size = 2 + rng.below(MAX_BORD_SIZE - 1);
+        let mut matrix: Vec<Vec<i8>> = (0..bord_size).map(|_| (0..bord_size).map(|_| if rng.below(10) < 3 { -1 } else { 0 }).collect()).collect();
+        let start_index = rng.below(bord_size * bord_size);
+        let mut end_index = rng.below(bord_size * bord_size - 1);
+
+        if end_index >= start_index {
+            end_index += 1;
+        }
+
+        matrix[start_index / bord_size][start_index % bord_size] = 1;
+        matrix[end_index / bord_size][end_index % bord_size] = 2;
+
+        return matrix;
+    }
+
+    /// Print a bord with one ligne of text by ligne of the matrix,
+    /// `S` for the start, `E` for the end, `#` for a wall and `.` for a free field.
+    fn bord_to_text(matrix: &[Vec<i8>]) -> String {
+        return matrix.iter().map(|line| line.iter().map(|value| match value {
+            1 => 'S',
+            2 => 'E',
+            -1 => '#',
+            _ => '.'
+        }).collect::<String>()).collect::<Vec<String>>().join("\n");
+    }
+
+    fn bord_from_text(text: &str) -> Vec<Vec<i8>> {
+        return text.lines().map(|line| line.trim().chars().map(|character| match character {
+            'S' => 1,
+            'E' => 2,
+            '#' => -1,
+            _ => 0
+        }).collect()).collect();
+    }
+
+    fn get_start_end_fields(matrix: &[Vec<i8>]) -> (Field, Field) {
+        let mut start_end = (Field::new(), Field::new());
+
+        for (x, line) in matrix.iter().enumerate() {
+            for (y, value) in line.iter().enumerate() {
+                let field = Field { coordinates: Point { x: Some(x), y: Some(y) }, value: Some(*value) };
+
+                match value {
+                    1 => start_end.0 = field,
+                    2 => start_end.1 = field,
+                    _ => {}
+                }
+            }
+        }
+
+        return start_end;
+    }
+
+    fn check_road(resolver_name: &str, matrix: &[Vec<i8>], road: &[Point], expected_cost: usize) -> Result<(), String> {
+        if let Some(violation) = get_path_violation(matrix, road) {
+            return Err(format!("{} returned a road with the violation {:?}", resolver_name, violation));
+        }
+
+        if road.len() - 1 != expected_cost {
+            return Err(format!("{} returned a road of cost {} instead of {}", resolver_name, road.len() - 1, expected_cost));
+        }
+
+        return Ok(());
+    }
+
+    /// Get the cost of the shorter road with the diagonal moves of the JPS, 10 for a straight move and 14 for a diagonal move.
+    /// A diagonal move is allowed when the two fields beside the diagonal are not walls.
+    fn get_eight_connectivity_cost(matrix: &[Vec<i8>]) -> Option<u32> {
+        let matrix_size = matrix.len() as i64;
+        let is_free = |x: i64, y: i64| x >= 0 && y >= 0 && x < matrix_size && y < matrix_size && matrix[x as usize][y as usize] != -1;
+        let start_end = get_start_end_fields(matrix);
+        let start = (start_end.0.coordinates.x? as i64, start_end.0.coordinates.y? as i64);
+        let end = (start_end.1.coordinates.x? as i64, start_end.1.coordinates.y? as i64);
+        let mut costs: Vec<Option<u32>> = vec![None; (matrix_size * matrix_size) as usize];
+        let mut open_list: BinaryHeap<Reverse<(u32, (i64, i64))>> = BinaryHeap::new();
+
+        open_list.push(Reverse((0, start)));
+
+        while let Some(Reverse((cost, (x, y)))) = open_list.pop() {
+            if costs[(x * matrix_size + y) as usize].is_some() {
+                continue;
+            }
+
+            costs[(x * matrix_size + y) as usize] = Some(cost);
+
+            if (x, y) == end {
+                return Some(cost);
+            }
+
+            for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1), (-1, -1), (-1, 1), (1, -1), (1, 1)] {
+                let is_diagonal = dx != 0 && dy != 0;
+
+                if is_free(x + dx, y + dy) && (! is_diagonal || (is_free(x + dx, y) && is_free(x, y + dy))) {
+                    open_list.push(Reverse((cost + if is_diagonal { 14 } else { 10 }, (x + dx, y + dy))));
+                }
+            }
+        }
+
+        return None;
+    }
+
+    /// Check a road with the diagonal moves of the JPS and get his cost, 10 for a straight move and 14 for a diagonal move.
+    fn get_eight_connectivity_road_cost(matrix: &[Vec<i8>], road: &[Point]) -> Result<u32, String> {
+        let start_end = get_start_end_fields(matrix);
+        let is_free = |x: usize, y: usize| matrix.get(x).and_then(|line| line.get(y)).is_some_and(|value| *value != -1);
+        let mut cost = 0;
+
+        if road.first() != Some(&start_end.0.coordinates) || road.last() != Some(&start_end.1.coordinates) {
+            return Err(String::from("jps_resolver_with_connectivity returned a road which does not go from the start to the end"));
+        }
+
+        for step in road.windows(2) {
+            let (from_x, from_y, to_x, to_y) = (step[0].x.unwrap(), step[0].y.unwrap(), step[1].x.unwrap(), step[1].y.unwrap());
+            let is_neighbour = from_x.abs_diff(to_x) <= 1 && from_y.abs_diff(to_y) <= 1 && step[0] != step[1];
+            let is_diagonal = from_x != to_x && from_y != to_y;
+
+            if ! is_neighbour || ! is_free(to_x, to_y) || (is_diagonal && ! (is_free(from_x, to_y) && is_free(to_x, from_y))) {
+                return Err(format!("jps_resolver_with_connectivity returned a wrong move from {:?} to {:?}", step[0], step[1]));
+            }
+
+            cost += if is_diagonal { 14 } else { 10 };
+        }
+
+        return Ok(cost);
+    }
+
+    /// Get the road of each resolver which returns one road between the start and the end of a bord.
+    /// The HPA* road is close to the shorter one but not always the shorter one.
+    fn get_single_roads(fs: &[Field], aps: &[u32], matrix_size: usize, start_end: (Field, Field)) -> Vec<(&'static str, Result<Path, &'static str>)> {
+        let (fs, aps) = (fs.to_vec(), aps.to_vec());
+
+        return vec![
+            ("a_star_resolver", a_star_resolver(fs.clone(), aps.clone(), matrix_size, start_end)),
+            ("bidirectional_a_star_resolver", bidirectional_a_star_resolver(fs.clone(), aps.clone(), matrix_size, start_end)),
+            ("a_star_nearest_road_resolver", a_star_nearest_road_resolver(fs.clone(), aps.clone(), matrix_size, (start_end.0, vec![start_end.1]))),
+            ("jps_resolver", jps_resolver(fs.clone(), aps.clone(), matrix_size, start_end)),
+            ("ida_star_resolver", ida_star_resolver(fs.clone(), aps.clone(), matrix_size, start_end)),
+            ("ara_star_resolver", ara_star_resolver(fs.clone(), aps.clone(), matrix_size, start_end, 2.5, 0.5, SearchBudget::MaxExpansions(100_000))
+                .and_then(|solutions| solutions.into_iter().last().map(|solution| solution.road).ok_or("no solution"))),
+            ("DStarLite::resolve", DStarLite::new(fs.clone(), aps.clone(), matrix_size, start_end).and_then(|mut d_star_lite| d_star_lite.resolve())),
+            ("hpa_star_resolver", hpa_star_resolver(fs.clone(), aps.clone(), matrix_size, start_end, 2)),
+            ("yen_k_shortest_roads_resolver", yen_k_shortest_roads_resolver(fs, aps, matrix_size, start_end, 1)
+                .and_then(|roads| roads.into_iter().next().ok_or("no road")))
+        ];
+    }
+
+    /// Get the distance from the start to the end given by each distance structure.
+    fn get_distances(fs: &[Field], aps: &[u32], matrix_size: usize, start_end: (Field, Field)) -> Vec<(&'static str, Option<usize>)> {
+        return vec![
+            ("DistanceTable::distance", DistanceTable::new(fs.to_vec(), aps.to_vec(), matrix_size).ok()
+                .and_then(|distance_table| distance_table.distance(start_end.0.coordinates, start_end.1.coordinates))),
+            ("FlowField::distance", a_star_multi_roads_flow_field(fs.to_vec(), aps.to_vec(), matrix_size, (start_end.0, vec![start_end.1])).ok()
+                .and_then(|flow_field| flow_field.distance(start_end.0.coordinates)))
+        ];
+    }
+
+    /// Check that the Theta* waypoints go from the start to the end and see each other,
+    /// the any-angle road can never be longer than the BFS road.
+    fn check_theta_star_road(matrix: &[Vec<i8>], road: &Path, bfs_cost: usize) -> Result<(), String> {
+        let start_end = get_start_end_fields(matrix);
+
+        if road.first() != Some(&start_end.0.coordinates) || road.last() != Some(&start_end.1.coordinates) {
+            return Err(String::from("theta_star_resolver returned a road which does not go from the start to the end"));
+        }
+
+        if road.points.windows(2).any(|waypoints| ! has_line_of_sight(matrix, waypoints[0], waypoints[1])) {
+            return Err(String::from("theta_star_resolver returned two waypoints without line of sight"));
+        }
+
+        if road.cost > bfs_cost {
+            return Err(format!("theta_star_resolver returned a road of cost {} bigger than {}", road.cost, bfs_cost));
+        }
+
+        return Ok(());
+    }
+
+    /// Run all the resolvers on a bord and check that they agree with the BFS.
+    fn check_bord(matrix: &[Vec<i8>]) -> Result<(), String> {
+        let (fs, aps) = fs_aps_from_matrix(matrix.to_vec()).map_err(|error| format!("fs_aps_from_matrix failed: {}", error))?;
+        let matrix_size = matrix.len();
+        let start_end = get_start_end_fields(matrix);
+        let bfs_road = bfs_resolver(fs.clone(), aps.clone(), matrix_size, start_end).ok();
+        let mut all_path: Vec<Path> = Vec::new();
+
+        dfs_fs_aps_recursive(fs.clone(), aps.clone(), start_end, matrix_size, &mut Vec::new(), &mut vec![start_end.0], &mut all_path).map_err(|error| format!("dfs_fs_aps_recursive failed: {}", error))?;
+
+        let mut roads: Vec<(&str, Option<Path>)> = get_single_roads(&fs, &aps, matrix_size, start_end).into_iter().map(|(resolver_name, road)| (resolver_name, road.ok())).collect();
+
+        roads.push(("dfs_fs_aps_recursive", all_path.iter().min_by_key(|path| path.cost).cloned()));
+
+        let theta_star_road = theta_star_resolver(fs.clone(), aps.clone(), matrix_size, start_end).ok();
+        let jps_eight_road = jps_resolver_with_connectivity(fs.clone(), aps.clone(), matrix_size, start_end, Connectivity::Eight).ok();
+        let distances = get_distances(&fs, &aps, matrix_size, start_end);
+
+        let bfs_road = match bfs_road {
+            Some(bfs_road) => bfs_road,
+            None => {
+                let found_road = roads.iter().find(|(_, road)| road.is_some()).map(|(resolver_name, _)| *resolver_name)
+                    .or(theta_star_road.map(|_| "theta_star_resolver"))
+                    .or(jps_eight_road.map(|_| "jps_resolver_with_connectivity"))
+                    .or(distances.iter().find(|(_, distance)| distance.is_some()).map(|(distance_name, _)| *distance_name));
+
+                return match found_road {
+                    Some(resolver_name) => Err(format!("{} found a road but bfs_resolver did not", resolver_name)),
+                    None => Ok(())
+                };
+            }
+        };
+        let bfs_cost = bfs_road.len() - 1;
+
+        check_road("bfs_resolver", matrix, &bfs_road.points, bfs_cost)?;
+
+        for (resolver_name, road) in roads.iter() {
+            match road {
+                // The HPA* road can be longer than the BFS road but never shorter.
+                Some(road) if *resolver_name == "hpa_star_resolver" => check_road(resolver_name, matrix, &road.points, bfs_cost.max(road.cost))?,
+                Some(road) => check_road(resolver_name, matrix, &road.points, bfs_cost)?,
+                None => return Err(format!("{} found no road but bfs_resolver did", resolver_name))
+            }
+        }
+
+        for (distance_name, distance) in distances.iter() {
+            if *distance != Some(bfs_cost) {
+                return Err(format!("{} gave the distance {:?} instead of {}", distance_name, distance, bfs_cost));
+            }
+        }
+
+        check_theta_star_road(matrix, &theta_star_road.ok_or("theta_star_resolver found no road but bfs_resolver did")?, bfs_cost)?;
+
+        let jps_eight_road = jps_eight_road.ok_or("jps_resolver_with_connectivity found no road but bfs_resolver did")?;
+        let jps_eight_cost = get_eight_connectivity_road_cost(matrix, &jps_eight_road.points)?;
+        let expected_eight_cost = get_eight_connectivity_cost(matrix);
+
+        if Some(jps_eight_cost) != expected_eight_cost || jps_eight_road.cost != jps_eight_cost as usize {
+            return Err(format!("jps_resolver_with_connectivity returned a road of cost {} (given {}) instead of {:?}", jps_eight_cost, jps_eight_road.cost, expected_eight_cost));
+        }
+
+        for path in all_path.iter() {
+            if let Some(violation) = get_path_violation(matrix, &path.points) {
+                return Err(format!("dfs_fs_aps_recursive returned a road with the violation {:?}", violation));
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// The same check as `check_bord` where a panic of a resolver is also a failure.
+    fn check_bord_without_panic(matrix: &[Vec<i8>]) -> Result<(), String> {
+        return panic::catch_unwind(AssertUnwindSafe(|| check_bord(matrix))).unwrap_or_else(|payload| Err(format!("a resolver panicked: {}", get_panic_message(payload))));
+    }
+
+    /// Get the smaller bords made from a bord: without one of his walls, or without one ligne and one column
+    /// which contain neither the start nor the end.
+    fn get_shrunk_bords(matrix: &[Vec<i8>]) -> Vec<Vec<Vec<i8>>> {
+        let mut shrunk_bords: Vec<Vec<Vec<i8>>> = Vec::new();
+
+        if matrix.len() > 2 {
+            for removed_index in 0..matrix.len() {
+                let is_used = matrix[removed_index].iter().any(|value| *value > 0) || matrix.iter().any(|line| line[removed_index] > 0);
+
+                if ! is_used {
+                    shrunk_bords.push(matrix.iter().enumerate().filter(|(x, _)| *x != removed_index).map(|(_, line)| {
+                        return line.iter().enumerate().filter(|(y, _)| *y != removed_index).map(|(_, value)| *value).collect();
+                    }).collect());
+                }
+            }
+        }
+
+        for (x, line) in matrix.iter().enumerate() {
+            for (y, value) in line.iter().enumerate() {
+                if *value == -1 {
+                    let mut shrunk_bord = matrix.to_vec();
+
+                    shrunk_bord[x][y] = 0;
+                    shrunk_bords.push(shrunk_bord);
+                }
+            }
+        }
+
+        return shrunk_bords;
+    }
+
+    /// Shrink a failing bord until none of his smaller bords fails.
+    fn shrink_bord(matrix: Vec<Vec<i8>>) -> (Vec<Vec<i8>>, String) {
+        let mut failure = check_bord_without_panic(&matrix).unwrap_err();
+        let mut matrix = matrix;
+
+        while let Some((shrunk_bord, shrunk_failure)) = get_shrunk_bords(&matrix).into_iter().find_map(|shrunk_bord| {
+            return check_bord_without_panic(&shrunk_bord).err().map(|shrunk_failure| (shrunk_bord, shrunk_failure));
+        }) {
+            matrix = shrunk_bord;
+            failure = shrunk_failure;
+        }
+
+        return (matrix, failure);
+    }
+
+    #[test]
+    fn differential_random_bords_test() {
+        let failures: Vec<(u64, Vec<Vec<i8>>, String)> = (0..BORDS_NUMBER).filter_map(|seed| {
+            let matrix = random_bord(seed);
+
+            return check_bord_without_panic(&matrix).err().map(|_| {
+                let (shrunk_bord, failure) = shrink_bord(matrix);
+
+                return (seed, shrunk_bord, failure);
+            });
+        }).collect();
+
+        if let Some((seed, shrunk_bord, failure)) = failures.first() {
+            panic!("{} bords failed, the first one with the seed {}: {}\n{}", failures.len(), seed, failure, bord_to_text(shrunk_bord));
+        }
+    }
+
+    #[test]
+    fn differential_unsolvable_bords_test() {
+        let bords = [
+            "S#.\n##.\n..E",
+            "S.#\n.#.\n#.E",
+            "S#\n#E"
+        ];
+
+        for bord_text in bords.iter() {
+            let matrix = bord_from_text(bord_text);
+            let (fs, aps) = fs_aps_from_matrix(matrix.clone()).unwrap();
+            let start_end = get_start_end_fields(&matrix);
+
+            let mut all_path: Vec<Path> = Vec::new();
+
+            assert!(bfs_resolver(fs.clone(), aps.clone(), matrix.len(), start_end).is_err());
+            assert!(theta_star_resolver(fs.clone(), aps.clone(), matrix.len(), start_end).is_err(), "theta_star_resolver\n{}", bord_text);
+            assert!(jps_resolver_with_connectivity(fs.clone(), aps.clone(), matrix.len(), start_end, Connectivity::Eight).is_err(), "jps_resolver_with_connectivity\n{}", bord_text);
+
+            for (resolver_name, road) in get_single_roads(&fs, &aps, matrix.len(), start_end) {
+                assert!(road.is_err(), "{}\n{}", resolver_name, bord_text);
+            }
+
+            for (distance_name, distance) in get_distances(&fs, &aps, matrix.len(), start_end) {
+                assert_eq!(distance, None, "{}\n{}", distance_name, bord_text);
+            }
+
+            assert_eq!(dfs_fs_aps_recursive(fs, aps, start_end, matrix.len(), &mut Vec::new(), &mut vec![start_end.0], &mut all_path), Ok(()));
+            assert!(all_path.is_empty());
+            assert_eq!(check_bord(&matrix), Ok(()), "\n{}", bord_text);
+        }
+    }
+
+    #[test]
+    fn random_bord_reproducible_test() {
+        for seed in 0..20 {
+            let matrix = random_bord(seed);
+
+            assert_eq!(random_bord(seed), matrix);
+            assert_eq!(bord_from_text(&bord_to_text(&matrix)), matrix);
+            assert_eq!(matrix.iter().flatten().filter(|value| **value == 1).count(), 1);
+            assert_eq!(matrix.iter().flatten().filter(|value| **value == 2).count(), 1);
+        }
+    }
+
+    #[test]
+    fn shrink_bord_test() {
+        let matrix = bord_from_text("S.#.\n.##.\n....\n...E");
+
+        assert_eq!(get_shrunk_bords(&matrix).len(), 5);
+        assert_eq!(get_shrunk_bords(&bord_from_text("S#\n#E")).len(), 2);
+        assert!(get_shrunk_bords(&bord_from_text("S.#\n...\n..E")).contains(&bord_from_text("S#\n.E")));
+    }
+}
@@ -0,0 +1,210 @@
+#![allow(clippy::needless_return)]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{ self, AssertUnwindSafe };
+    use esgi_arena_resolver_algorithms::graph::*;
+    use esgi_arena_resolver_algorithms::a_star::*;
+    use esgi_arena_resolver_algorithms::bfs::*;
+    use esgi_arena_resolver_algorithms::dfs::*;
+    use esgi_arena_resolver_algorithms::jps::{ jps_resolver_with_connectivity, Connectivity };
+    use esgi_arena_resolver_algorithms::ida_star::ida_star_resolver;
+    use esgi_arena_resolver_algorithms::ara_star::{ ara_star_resolver, SearchBudget };
+    use esgi_arena_resolver_algorithms::d_star_lite::DStarLite;
+    use esgi_arena_resolver_algorithms::theta_star::{ theta_star_resolver, has_line_of_sight };
+    use esgi_arena_resolver_algorithms::tsp::tsp_resolver;
+    use esgi_arena_resolver_algorithms::yen::yen_k_shortest_roads_resolver;
+    use esgi_arena_resolver_algorithms::cbs::cbs_resolver;
+    use esgi_arena_resolver_algorithms::cooperative::cooperative_a_star_resolver;
+    use esgi_arena_resolver_algorithms::timed_bord::{ timed_a_star_resolver, TimedBord, FieldSchedule };
+    use esgi_arena_resolver_algorithms::hpa_star::{ hpa_star_resolver, HierarchicalGraph };
+    use esgi_arena_resolver_algorithms::distance_table::DistanceTable;
+    use esgi_arena_resolver_algorithms::flow_field::flow_field_resolver;
+    use esgi_arena_resolver_algorithms::path_validation::validate_path;
+    use esgi_arena_resolver_algorithms::turn_penalty::{ a_star_turn_penalty_resolver, TurnPenalties, Move };
+    use crate::common::{ XorShift, get_panic_message };
+
+    /// The number of random inputs given to each public function.
+    const CASES_NUMBER: u64 = 2000;
+
+    /// Random inputs, often wrong: matrices without square form, points outside of the bord,
+    /// matrix sizes which do not match the FS APS graph, costs and timesteps at the limit of their type.
+    struct FuzzCase {
+        matrix: Vec<Vec<i8>>,
+        fs: Vec<Field>,
+        aps: Vec<u32>,
+        matrix_size: usize,
+        points: Vec<Point>,
+        fields: Vec<Field>,
+        number: usize,
+        penalties: TurnPenalties,
+        heading: Option<Move>,
+        blocked_times: Vec<usize>
+    }
+
+    fn pick<T: Clone>(rng: &mut XorShift, values: &[T]) -> T {
+        return values[rng.below(values.len())].clone();
+    }
+
+    fn random_coordinate(rng: &mut XorShift, bord_size: usize) -> Option<usize> {
+        return match rng.below(8) {
+            0 => None,
+            1 => Some(usize::MAX),
+            2 => Some(bord_size + rng.below(3)),
+            _ => Some(rng.below(bord_size.max(1)))
+        };
+    }
+
+    fn random_case(seed: u64) -> FuzzCase {
+        let mut rng = XorShift::new(seed);
+        let bord_size = pick(&mut rng, &[0, 1, 2, 2, 3, 3, 4, 4, 5, 21]);
+        let values: [i8; 9] = [-1, -1, 0, 0, 0, 1, 2, 3, i8::MIN];
+        let matrix: Vec<Vec<i8>> = (0..bord_size).map(|_| {
+            let line_size = if rng.below(10) == 0 { rng.below(bord_size + 2) } else { bord_size };
+
+            return (0..line_size).map(|_| pick(&mut rng, &values)).collect();
+        }).collect();
+        let (mut fs, mut aps) = fs_aps_from_matrix(matrix.clone()).unwrap_or_default();
+
+        match rng.below(10) {
+            0 => { aps.truncate(rng.below(aps.len() + 1)); },
+            1 => { fs.truncate(rng.below(fs.len() + 1)); },
+            2 => aps.iter_mut().for_each(|aps_value| *aps_value = aps_value.wrapping_mul(3)),
+            _ => {}
+        }
+
+        let matrix_size = match rng.below(10) {
+            0 => 0,
+            1 => bord_size + 1,
+            2 => usize::MAX,
+            _ => bord_size
+        };
+        let points: Vec<Point> = (0..1 + rng.below(4)).map(|_| Point { x: random_coordinate(&mut rng, bord_size), y: random_coordinate(&mut rng, bord_size) }).collect();
+        let fields: Vec<Field> = points.iter().map(|point| Field { coordinates: *point, value: pick(&mut rng, &[None, Some(-1), Some(0), Some(1), Some(2)]) }).collect();
+        let number = pick(&mut rng, &[0, 1, 2, 3, usize::MAX]);
+        let costs: [u32; 6] = [0, 1, 2, 7, u32::MAX / 2, u32::MAX];
+        let penalties = TurnPenalties { move_cost: pick(&mut rng, &costs), quarter_turn_cost: pick(&mut rng, &costs), half_turn_cost: pick(&mut rng, &costs) };
+        let heading = pick(&mut rng, &[None, Some(Move::Up), Some(Move::Down), Some(Move::Left), Some(Move::Right)]);
+        let blocked_times: Vec<usize> = (0..rng.below(4)).map(|_| pick(&mut rng, &[0, 1, 2, 5, usize::MAX - 1, usize::MAX])).collect();
+
+        return FuzzCase { matrix, fs, aps, matrix_size, points, fields, number, penalties, heading, blocked_times };
+    }
+
+    type PublicFunction = (&'static str, fn(&FuzzCase));
+
+    fn get_start_end(case: &FuzzCase) -> (Field, Field) {
+        return (case.fields[0], case.fields[case.fields.len() - 1]);
+    }
+
+    fn get_public_functions() -> Vec<PublicFunction> {
+        return vec![
+            ("Point::get_index", |case| { let _ = case.points[0].get_index(case.matrix_size); }),
+            ("Point::from_index", |case| { let _ = Point::from_index(case.number, case.matrix_size); }),
+            ("get_start_to_end_points", |case| { let _ = get_start_to_end_points(case.matrix.clone()); }),
+            ("get_start_to_end_points_multi_roads", |case| { let _ = get_start_to_end_points_multi_roads(case.matrix.clone()); }),
+            ("fs_aps_from_matrix", |case| { let _ = fs_aps_from_matrix(case.matrix.clone()); }),
+            ("get_field_by_index", |case| { let _ = get_field_by_index(case.matrix.clone(), case.points[0]); }),
+            ("get_element_childs_from_fs_aps", |case| { let _ = get_element_childs_from_fs_aps(case.fs.clone(), case.aps.clone(), case.number); }),
+            ("remove_end_point_from_aps", |case| { let _ = remove_end_point_from_aps(&mut case.fs.clone(), case.points[0]); }),
+            ("get_manhattan_distance_heuristic", |case| { let _ = get_manhattan_distance_heuristic(case.points[0], case.points[case.points.len() - 1]); }),
+            ("a_star_resolver", |case| { let _ = a_star_resolver(case.fs.clone(), case.aps.clone(), case.matrix_size, get_start_end(case)); }),
+            ("a_star_resolver_with_stats", |case| { let _ = a_star_resolver_with_stats(case.fs.clone(), case.aps.clone(), case.matrix_size, get_start_end(case), &mut SearchStats::new()); }),
+            ("a_star_uniform_cost_resolver", |case| {
+                let _ = a_star_uniform_cost_resolver(case.fs.clone(), case.aps.clone(), case.matrix_size, get_start_end(case), 100_u8);
+                let _ = a_star_uniform_cost_resolver(case.fs.clone(), case.aps.clone(), case.matrix_size, get_start_end(case), f64::MAX);
+            }),
+            ("a_star_resolver_with_limits", |case| { let _ = a_star_resolver_with_limits(case.fs.clone(), case.aps.clone(), case.matrix_size, get_start_end(case), &SearchLimits::new()); }),
+            ("AStarSearch", |case| {
+                if let Ok(mut search) = AStarSearch::new(case.fs.clone(), case.aps.clone(), case.matrix_size, get_start_end(case)) {
+                    search.by_ref().take(1000).for_each(drop);
+                    let _ = search.road_to_closest_field();
+                }
+            }),
+            ("bidirectional_a_star_resolver", |case| { let _ = bidirectional_a_star_resolver(case.fs.clone(), case.aps.clone(), case.matrix_size, get_start_end(case)); }),
+            ("a_star_multi_roads_resolver", |case| { let _ = a_star_multi_roads_resolver(&mut case.fs.clone(), case.aps.clone(), case.matrix_size, (case.fields[0], case.fields[1..].to_vec())); }),
+            ("a_star_multi_roads_flow_field", |case| { let _ = a_star_multi_roads_flow_field(case.fs.clone(), case.aps.clone(), case.matrix_size, (case.fields[0], case.fields[1..].to_vec())); }),
+            ("a_star_nearest_roads_resolver", |case| { let _ = a_star_nearest_roads_resolver(case.fs.clone(), case.aps.clone(), case.matrix_size, (case.fields[0], case.fields[1..].to_vec()), case.number); }),
+            ("a_star_nearest_road_resolver", |case| { let _ = a_star_nearest_road_resolver(case.fs.clone(), case.aps.clone(), case.matrix_size, (case.fields[0], case.fields[1..].to_vec())); }),
+            ("bfs_resolver", |case| { let _ = bfs_resolver(case.fs.clone(), case.aps.clone(), case.matrix_size, get_start_end(case)); }),
+            ("bfs_distance_map", |case| { let _ = bfs_distance_map(case.fs.clone(), case.aps.clone(), case.matrix_size, case.fields[0]); }),
+            ("dfs_fs_aps_recursive", |case| { let _ = dfs_fs_aps_recursive(case.fs.clone(), case.aps.clone(), get_start_end(case), case.matrix_size, &mut Vec::new(), &mut case.fields[..1].to_vec(), &mut Vec::new()); }),
+            ("dfs_all_paths_with_stats", |case| { let _ = dfs_all_paths_with_stats(case.fs.clone(), case.aps.clone(), get_start_end(case), case.matrix_size, &mut SearchStats::new()); }),
+            ("dfs_all_paths_with_limits", |case| { let _ = dfs_all_paths_with_limits(case.fs.clone(), case.aps.clone(), get_start_end(case), case.matrix_size, &SearchLimits::new()); }),
+            ("jps_resolver_with_connectivity", |case| {
+                let _ = jps_resolver_with_connectivity(case.fs.clone(), case.aps.clone(), case.matrix_size, get_start_end(case), Connectivity::Four);
+                let _ = jps_resolver_with_connectivity(case.fs.clone(), case.aps.clone(), case.matrix_size, get_start_end(case), Connectivity::Eight);
+            }),
+            ("ida_star_resolver", |case| { let _ = ida_star_resolver(case.fs.clone(), case.aps.clone(), case.matrix_size, get_start_end(case)); }),
+            ("ara_star_resolver", |case| { let _ = ara_star_resolver(case.fs.clone(), case.aps.clone(), case.matrix_size, get_start_end(case), 2.5, 0.5, SearchBudget::MaxExpansions(1000)); }),
+            ("DStarLite", |case| {
+                if let Ok(mut d_star_lite) = DStarLite::new(case.fs.clone(), case.aps.clone(), case.matrix_size, get_start_end(case)) {
+                    let _ = d_star_lite.resolve();
+                    let _ = d_star_lite.replan(case.points[case.points.len() - 1], case.fields.clone());
+                }
+            }),
+            ("theta_star_resolver", |case| { let _ = theta_star_resolver(case.fs.clone(), case.aps.clone(), case.matrix_size, get_start_end(case)); }),
+            ("has_line_of_sight", |case| { let _ = has_line_of_sight(&case.matrix, case.points[0], case.points[case.points.len() - 1]); }),
+            ("tsp_resolver", |case| { let _ = tsp_resolver(case.fs.clone(), case.aps.clone(), case.matrix_size, (case.fields[0], case.fields[1..].to_vec())); }),
+            ("yen_k_shortest_roads_resolver", |case| { let _ = yen_k_shortest_roads_resolver(case.fs.clone(), case.aps.clone(), case.matrix_size, get_start_end(case), case.number.min(4)); }),
+            ("cbs_resolver", |case| { let _ = cbs_resolver(case.fs.clone(), case.aps.clone(), case.matrix_size, case.fields.iter().zip(case.fields.iter().rev()).map(|(start, end)| (*start, *end)).collect()); }),
+            ("cooperative_a_star_resolver", |case| { let _ = cooperative_a_star_resolver(case.fs.clone(), case.aps.clone(), case.matrix_size, case.fields.iter().zip(case.fields.iter().rev()).map(|(start, end)| (*start, *end)).collect(), Some(case.number)); }),
+            ("timed_a_star_resolver", |case| {
+                let mut timed_bord = TimedBord::new(case.matrix_size);
+                let _ = timed_bord.add_schedule(case.points[0], FieldSchedule::Periodic { period: case.number, blocked_phases: vec![case.number] });
+                let _ = timed_bord.add_schedule(case.points[case.points.len() - 1], FieldSchedule::Explicit { blocked_times: case.blocked_times.clone() });
+                let _ = timed_bord.add_patrol(case.points.clone());
+                let _ = timed_bord.is_blocked_at(case.points[0], case.number);
+                let _ = timed_a_star_resolver(case.fs.clone(), case.aps.clone(), case.matrix_size, get_start_end(case), &timed_bord);
+            }),
+            ("HierarchicalGraph", |case| {
+                if let Ok(mut hierarchical_graph) = HierarchicalGraph::new(case.fs.clone(), case.aps.clone(), case.matrix_size, case.number) {
+                    let _ = hierarchical_graph.update_field(case.points[0], -1);
+                    let _ = hierarchical_graph.resolve(get_start_end(case));
+                }
+            }),
+            ("hpa_star_resolver", |case| { let _ = hpa_star_resolver(case.fs.clone(), case.aps.clone(), case.matrix_size, get_start_end(case), 2); }),
+            ("DistanceTable", |case| {
+                if let Ok(distance_table) = DistanceTable::new(case.fs.clone(), case.aps.clone(), case.matrix_size) {
+                    let _ = distance_table.road(case.points[0], case.points[case.points.len() - 1]);
+                    let bytes = distance_table.to_bytes();
+                    let _ = DistanceTable::from_bytes(&bytes[..bytes.len().min(case.number)]);
+                }
+            }),
+            ("flow_field_resolver", |case| {
+                if let Ok(flow_field) = flow_field_resolver(case.fs.clone(), case.aps.clone(), case.matrix_size, case.fields[1..].to_vec()) {
+                    let _ = flow_field.road_from(case.points[0]);
+                }
+            }),
+            ("validate_path", |case| { let _ = validate_path(&case.matrix, &case.points); }),
+            ("Path", |case| {
+                let path = Path::new(case.points.clone());
+                let _ = path.is_valid_on(&case.matrix);
+                let _ = (path.moves(), path.turns());
+                let _ = path.clone().concat(path.reverse());
+            }),
+            ("a_star_turn_penalty_resolver", |case| {
+                let _ = case.heading.map(|heading| case.penalties.move_cost(Some(heading), Move::Up));
+                let _ = a_star_turn_penalty_resolver(case.fs.clone(), case.aps.clone(), case.matrix_size, get_start_end(case), case.penalties, case.heading);
+            })
+        ];
+    }
+
+    #[test]
+    fn public_functions_never_panic_test() {
+        let mut failures: Vec<String> = Vec::new();
+
+        for seed in 0..CASES_NUMBER {
+            let case = random_case(seed);
+
+            for (function_name, public_function) in get_public_functions().iter() {
+                if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| public_function(&case))) {
+                    failures.push(format!("{} panicked with the seed {} ({}): matrix {:?}, matrix size {}, fields {:?}, number {}", function_name, seed, get_panic_message(payload), case.matrix, case.matrix_size, case.fields, case.number));
+                }
+            }
+        }
+
+        assert!(failures.is_empty(), "{} panics, the first ones:\n{}", failures.len(), failures.iter().take(10).cloned().collect::<Vec<String>>().join("\n"));
+    }
+}
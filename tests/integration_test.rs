@@ -1,8 +1,31 @@
+#![allow(clippy::needless_return)]
+
 #[cfg(test)]
 mod tests {
+    use std::time::{ Duration, Instant };
+    use std::sync::Arc;
+    use std::sync::atomic::{ AtomicBool, Ordering };
+    use std::thread;
     use esgi_arena_resolver_algorithms::a_star::*;
     use esgi_arena_resolver_algorithms::graph::*;
     use esgi_arena_resolver_algorithms::chinese_rings::*;
+    use esgi_arena_resolver_algorithms::bfs::*;
+    use esgi_arena_resolver_algorithms::jps::*;
+    use esgi_arena_resolver_algorithms::ida_star::*;
+    use esgi_arena_resolver_algorithms::ara_star::*;
+    use esgi_arena_resolver_algorithms::d_star_lite::*;
+    use esgi_arena_resolver_algorithms::theta_star::*;
+    use esgi_arena_resolver_algorithms::tsp::*;
+    use esgi_arena_resolver_algorithms::yen::*;
+    use esgi_arena_resolver_algorithms::dfs::*;
+    use esgi_arena_resolver_algorithms::cbs::{ cbs_resolver, cbs_resolver_with_stats };
+    use esgi_arena_resolver_algorithms::cooperative::{ cooperative_a_star_resolver, cooperative_a_star_resolver_with_stats };
+    use esgi_arena_resolver_algorithms::timed_bord::{ timed_a_star_resolver, timed_a_star_resolver_with_stats, TimedBord, FieldSchedule };
+    use esgi_arena_resolver_algorithms::hpa_star::{ hpa_star_resolver, hpa_star_resolver_with_stats, HierarchicalGraph };
+    use esgi_arena_resolver_algorithms::distance_table::DistanceTable;
+    use esgi_arena_resolver_algorithms::path_validation::{ validate_path, PathReport, PathViolation };
+    use esgi_arena_resolver_algorithms::path::{ Move, Turn };
+    use esgi_arena_resolver_algorithms::turn_penalty::{ a_star_turn_penalty_resolver, a_star_turn_penalty_resolver_with_stats, TurnPenalties };
     
     fn testing_data() -> (Vec<Vec<i8>>, Vec<Field>, Vec<u32>) {
         let matrix_example: Vec<Vec<i8>> = vec![
@@ -260,10 +283,8 @@ mod tests {
         }];
 
         let fs_example: Vec<Field> = matrix_first_line.into_iter()
-            .chain(matrix_second_line
-                .into_iter())
-            .chain(matrix_third_line
-                .into_iter())
+            .chain(matrix_second_line)
+            .chain(matrix_third_line)
             .collect();
 
 
@@ -457,10 +478,8 @@ mod tests {
         }];
 
         let fs_example: Vec<Field> = matrix_first_line.into_iter()
-            .chain(matrix_second_line
-                .into_iter())
-            .chain(matrix_third_line
-                .into_iter())
+            .chain(matrix_second_line)
+            .chain(matrix_third_line)
             .collect();
 
 
@@ -663,7 +682,7 @@ mod tests {
             y: Some(1)
         };
         let result_field = Field {
-            coordinates: index_field.clone(),
+            coordinates: index_field,
             value: Some(2)
         };
 
@@ -689,7 +708,7 @@ mod tests {
             value: Some(2)
         });
 
-        assert_eq!(a_star_resolver(fs, aps, 2, start_end).unwrap(), expected_output);
+        assert_eq!(a_star_resolver(fs, aps, 2, start_end).unwrap(), Path::new(expected_output));
     }
 
     #[test]
@@ -742,7 +761,7 @@ mod tests {
             }
         );
 
-        assert_eq!(a_star_resolver(fs, aps, 3, start_end).unwrap(), expected_output);
+        assert_eq!(a_star_resolver(fs, aps, 3, start_end).unwrap(), Path::new(expected_output));
     }
 
     #[test]
@@ -838,7 +857,7 @@ mod tests {
             }]
         ];
 
-        assert_eq!(a_star_multi_roads_resolver(&mut fs, aps, 3, start_end).unwrap(), expected_output);
+        assert_eq!(a_star_multi_roads_resolver(&mut fs, aps, 3, start_end).unwrap(), expected_output.into_iter().map(Path::new).collect::<Vec<Path>>());
     }
 
     #[test]
@@ -861,4 +880,1392 @@ mod tests {
         assert_eq!(chinese_rings_resolver(size), expected_output);
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn bfs_resolver_heavy_test() {
+        let (_, fs, aps) = testing_data_heavy_matrix();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(2),
+                    y: Some(0)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point{
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+
+        assert_eq!(bfs_resolver(fs.clone(), aps.clone(), 3, start_end).unwrap(), a_star_resolver(fs, aps, 3, start_end).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "It seem that it has no end to this level")]
+    fn bfs_resolver_invalid_matrix() {
+        let (_, fs, aps) = testing_data_no_road();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(1)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point{
+                    x: Some(1),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+        bfs_resolver(fs, aps, 2, start_end).unwrap();
+    }
+
+    #[test]
+    fn bfs_distance_map_test() {
+        let (_, fs, aps) = testing_data_heavy_matrix();
+        let start = Field {
+            coordinates: Point {
+                x: Some(2),
+                y: Some(0)
+            },
+            value: Some(1)
+        };
+        let expected_output = vec![Some(6), Some(5), Some(4), None, None, Some(3), Some(0), Some(1), Some(2)];
+
+        assert_eq!(bfs_distance_map(fs, aps, 3, start).unwrap(), expected_output);
+    }
+
+    #[test]
+    fn a_star_resolver_shorter_road_test() {
+        let matrix: Vec<Vec<i8>> = vec![
+            vec![0, 0, 0, 0, 0, 0, 0, 0, -1],
+            vec![0, -1, -1, 0, -1, 0, -1, 1, 0],
+            vec![0, 0, 0, -1, -1, 0, 0, 0, 0],
+            vec![0, 0, -1, -1, 0, 0, 0, -1, -1],
+            vec![0, 0, -1, -1, -1, 0, -1, 0, 0],
+            vec![-1, 0, 0, 0, -1, -1, -1, 0, 0],
+            vec![0, 0, 0, 2, -1, 0, 0, 0, 0],
+            vec![0, 0, 0, 0, 0, 0, 0, 0, -1],
+            vec![-1, 0, 0, 0, 0, 0, -1, 0, 0]
+        ];
+        let (start, end) = get_start_to_end_points(matrix.clone()).unwrap();
+        let (fs, aps) = fs_aps_from_matrix(matrix.clone()).unwrap();
+        let start_end = (get_field_by_index(matrix.clone(), start).unwrap(), get_field_by_index(matrix, end).unwrap());
+
+        assert_eq!(a_star_resolver(fs.clone(), aps.clone(), 9, start_end).unwrap().len(), bfs_resolver(fs, aps, 9, start_end).unwrap().len());
+    }
+
+    #[test]
+    fn bidirectional_a_star_resolver_heavy_test() {
+        let (_, fs, aps) = testing_data_heavy_matrix();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(2),
+                    y: Some(0)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point{
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+
+        assert_eq!(bidirectional_a_star_resolver(fs.clone(), aps.clone(), 3, start_end).unwrap(), a_star_resolver(fs, aps, 3, start_end).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "It seem that it has no end to this level")]
+    fn bidirectional_a_star_resolver_invalid_matrix() {
+        let (_, fs, aps) = testing_data_no_road();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(1)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point{
+                    x: Some(1),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+        bidirectional_a_star_resolver(fs, aps, 2, start_end).unwrap();
+    }
+
+    #[test]
+    fn jps_resolver_heavy_test() {
+        let (_, fs, aps) = testing_data_heavy_matrix();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(2),
+                    y: Some(0)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point{
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+
+        assert_eq!(jps_resolver(fs.clone(), aps.clone(), 3, start_end).unwrap(), a_star_resolver(fs, aps, 3, start_end).unwrap());
+    }
+
+    #[test]
+    fn jps_resolver_open_bord_test() {
+        let mut matrix: Vec<Vec<i8>> = vec![vec![0; 12]; 12];
+        matrix[0][0] = 1;
+        matrix[11][11] = 2;
+        matrix[5][3] = -1;
+        matrix[6][8] = -1;
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point{
+                    x: Some(11),
+                    y: Some(11)
+                },
+                value: Some(2)
+            }
+        );
+
+        assert_eq!(jps_resolver(fs.clone(), aps.clone(), 12, start_end).unwrap().len(), 23);
+        assert_eq!(jps_resolver_with_connectivity(fs, aps, 12, start_end, Connectivity::Eight).unwrap().len(), 12);
+    }
+
+    #[test]
+    #[should_panic(expected = "It seem that it has no end to this level")]
+    fn jps_resolver_invalid_matrix() {
+        let (_, fs, aps) = testing_data_no_road();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(1)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point{
+                    x: Some(1),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+        jps_resolver_with_connectivity(fs, aps, 2, start_end, Connectivity::Eight).unwrap();
+    }
+
+    #[test]
+    fn ida_star_resolver_heavy_test() {
+        let (_, fs, aps) = testing_data_heavy_matrix();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(2),
+                    y: Some(0)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point{
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+
+        assert_eq!(ida_star_resolver(fs.clone(), aps.clone(), 3, start_end).unwrap(), a_star_resolver(fs, aps, 3, start_end).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "It seem that it has no end to this level")]
+    fn ida_star_resolver_invalid_matrix() {
+        let (_, fs, aps) = testing_data_no_road();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(1)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point{
+                    x: Some(1),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+        ida_star_resolver(fs, aps, 2, start_end).unwrap();
+    }
+
+    #[test]
+    fn ara_star_resolver_deadline_test() {
+        let (_, fs, aps) = testing_data_heavy_matrix();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(2),
+                    y: Some(0)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point{
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+        let deadline = Instant::now() + Duration::from_secs(5);
+
+        let solutions = ara_star_resolver(fs.clone(), aps.clone(), 3, start_end, 2.5, 0.5, SearchBudget::Deadline(deadline)).unwrap();
+
+        assert_eq!(solutions.last().unwrap().road, a_star_resolver(fs, aps, 3, start_end).unwrap());
+        assert_eq!(solutions.last().unwrap().suboptimality_bound, 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "It seem that it has no end to this level")]
+    fn ara_star_resolver_invalid_matrix() {
+        let (_, fs, aps) = testing_data_no_road();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(1)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point{
+                    x: Some(1),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+        ara_star_resolver(fs, aps, 2, start_end, 2.0, 0.5, SearchBudget::MaxExpansions(100)).unwrap();
+    }
+
+    #[test]
+    fn d_star_lite_replan_test() {
+        let (_, fs, aps) = testing_data_heavy_matrix();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(2),
+                    y: Some(0)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point{
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+        let mut d_star_lite = DStarLite::new(fs.clone(), aps.clone(), 3, start_end).unwrap();
+
+        assert_eq!(d_star_lite.resolve().unwrap(), a_star_resolver(fs, aps, 3, start_end).unwrap());
+
+        let new_start = Point {
+            x: Some(2),
+            y: Some(1)
+        };
+        let removed_wall = vec![Field {
+            coordinates: Point {
+                x: Some(1),
+                y: Some(1)
+            },
+            value: Some(0)
+        }];
+        let expected_output = vec![Point {
+            x: Some(2),
+            y: Some(1)
+        },
+        Point {
+            x: Some(1),
+            y: Some(1)
+        },
+        Point {
+            x: Some(0),
+            y: Some(1)
+        },
+        Point {
+            x: Some(0),
+            y: Some(0)
+        }];
+
+        assert_eq!(d_star_lite.replan(new_start, removed_wall).unwrap().points, expected_output);
+    }
+
+    #[test]
+    fn theta_star_resolver_heavy_test() {
+        let (matrix, fs, aps) = testing_data_heavy_matrix();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(2),
+                    y: Some(0)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point{
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+        let expected_output = vec![Point {
+            x: Some(2),
+            y: Some(0)
+        },
+        Point {
+            x: Some(2),
+            y: Some(2)
+        },
+        Point {
+            x: Some(0),
+            y: Some(2)
+        },
+        Point {
+            x: Some(0),
+            y: Some(0)
+        }];
+
+        let waypoints = theta_star_resolver(fs, aps, 3, start_end).unwrap();
+
+        assert_eq!(waypoints.points, expected_output);
+        assert!(waypoints.points.windows(2).all(|waypoint_segment| has_line_of_sight(&matrix, waypoint_segment[0], waypoint_segment[1])));
+    }
+
+    #[test]
+    #[should_panic(expected = "It seem that it has no end to this level")]
+    fn theta_star_resolver_invalid_matrix() {
+        let (_, fs, aps) = testing_data_no_road();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(1)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point{
+                    x: Some(1),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+        theta_star_resolver(fs, aps, 2, start_end).unwrap();
+    }
+
+    #[test]
+    fn a_star_nearest_roads_resolver_test() {
+        let (_, fs, aps) = testing_data_heavy_matrix_multi_end();
+        let start_end = (Field {
+            coordinates: Point {
+                x: Some(2),
+                y: Some(0)
+            },
+            value: Some(1)
+        }, vec![Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(2)
+            },
+            Field {
+                coordinates: Point {
+                    x: Some(2),
+                    y: Some(2)
+                },
+                value: Some(2)
+            }
+        ]);
+
+        let roads = a_star_nearest_roads_resolver(fs.clone(), aps.clone(), 3, start_end.clone(), 5).unwrap();
+
+        assert_eq!(roads.len(), 2);
+        assert_eq!(roads[0].last(), Some(&Point { x: Some(2), y: Some(2) }));
+        assert_eq!(roads[1].len(), 7);
+        assert_eq!(a_star_nearest_road_resolver(fs, aps, 3, start_end).unwrap(), roads[0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "It seem that it has no end to this level")]
+    fn a_star_nearest_road_resolver_invalid_matrix() {
+        let (_, fs, aps) = testing_data_no_road();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(1)
+                },
+                value: Some(1)
+            },
+            vec![Field {
+                coordinates: Point{
+                    x: Some(1),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }]
+        );
+        a_star_nearest_road_resolver(fs, aps, 2, start_end).unwrap();
+    }
+
+    #[test]
+    fn tsp_resolver_test() {
+        let (_, fs, aps) = testing_data_heavy_matrix_multi_end();
+        let start_end = (Field {
+            coordinates: Point {
+                x: Some(2),
+                y: Some(0)
+            },
+            value: Some(1)
+        }, vec![Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(2)
+            },
+            Field {
+                coordinates: Point {
+                    x: Some(2),
+                    y: Some(2)
+                },
+                value: Some(2)
+            }
+        ]);
+
+        let road = tsp_resolver(fs, aps, 3, start_end.clone()).unwrap();
+
+        assert_eq!(road.len(), 7);
+        assert_eq!(road.first(), Some(&start_end.0.coordinates));
+        assert_eq!(road.last(), Some(&Point { x: Some(0), y: Some(0) }));
+
+        for end_point in start_end.1.iter() {
+            assert!(road.points.contains(&end_point.coordinates));
+        }
+    }
+
+    #[test]
+    fn tsp_resolver_many_end_points_test() {
+        let matrix_size = 20;
+        let mut matrix: Vec<Vec<i8>> = vec![vec![0; matrix_size]; matrix_size];
+        let mut end_points: Vec<Field> = Vec::new();
+
+        matrix[0][0] = 1;
+
+        for (y, value) in matrix[matrix_size - 1].iter_mut().enumerate().skip(1) {
+            *value = 2;
+            end_points.push(Field {
+                coordinates: Point {
+                    x: Some(matrix_size - 1),
+                    y: Some(y)
+                },
+                value: Some(2)
+            });
+        }
+
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let start_point = Field {
+            coordinates: Point {
+                x: Some(0),
+                y: Some(0)
+            },
+            value: Some(1)
+        };
+
+        let road = tsp_resolver(fs, aps, matrix_size, (start_point, end_points.clone())).unwrap();
+
+        assert_eq!(road.len(), 39);
+
+        for end_point in end_points.iter() {
+            assert!(road.points.contains(&end_point.coordinates));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "It seem that it has no end to this level")]
+    fn tsp_resolver_invalid_matrix() {
+        let (_, fs, aps) = testing_data_no_road();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(1)
+                },
+                value: Some(1)
+            },
+            vec![Field {
+                coordinates: Point{
+                    x: Some(1),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }]
+        );
+        tsp_resolver(fs, aps, 2, start_end).unwrap();
+    }
+
+    #[test]
+    fn yen_k_shortest_roads_resolver_test() {
+        let (_, fs, aps) = testing_data_heavy_matrix();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(2),
+                    y: Some(0)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+
+        let roads = yen_k_shortest_roads_resolver(fs.clone(), aps.clone(), 3, start_end, 3).unwrap();
+
+        assert_eq!(roads.len(), 1);
+        assert_eq!(roads[0], a_star_resolver(fs, aps, 3, start_end).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "It seem that it has no end to this level")]
+    fn yen_k_shortest_roads_resolver_invalid_matrix() {
+        let (_, fs, aps) = testing_data_no_road();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(1)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point{
+                    x: Some(1),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+        yen_k_shortest_roads_resolver(fs, aps, 2, start_end, 3).unwrap();
+    }
+
+    #[test]
+    fn resolvers_with_stats_test() {
+        let (_, fs, aps) = testing_data_heavy_matrix();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(2),
+                    y: Some(0)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+        let mut a_star_stats = SearchStats::new();
+        let mut bfs_stats = SearchStats::new();
+        let mut dfs_stats = SearchStats::new();
+
+        let a_star_road = a_star_resolver_with_stats(fs.clone(), aps.clone(), 3, start_end, &mut a_star_stats).unwrap();
+        let bfs_road = bfs_resolver_with_stats(fs.clone(), aps.clone(), 3, start_end, &mut bfs_stats).unwrap();
+        let all_path = dfs_all_paths_with_stats(fs, aps, start_end, 3, &mut dfs_stats).unwrap();
+
+        assert_eq!(a_star_road, bfs_road);
+        assert_eq!(all_path.len(), 1);
+        assert_eq!(a_star_stats.path_cost, Some(6));
+        assert_eq!(bfs_stats.path_cost, Some(6));
+        assert_eq!(dfs_stats.path_cost, Some(6));
+        assert_eq!(dfs_stats.max_open_list_size, 7);
+
+        for stats in [a_star_stats, bfs_stats, dfs_stats].iter() {
+            assert!(stats.nodes_expanded >= 7);
+            assert!(stats.nodes_generated >= 6);
+            assert!(stats.max_open_list_size >= 1);
+        }
+    }
+
+    #[test]
+    fn other_resolvers_with_stats_test() {
+        let (_, fs, aps) = testing_data_heavy_matrix();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(2),
+                    y: Some(0)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+        let mut all_stats: Vec<(&str, SearchStats)> = Vec::new();
+        let mut stats = SearchStats::new();
+
+        jps_resolver_with_stats(fs.clone(), aps.clone(), 3, start_end, Connectivity::Four, &mut stats).unwrap();
+        all_stats.push(("jps", stats));
+
+        ida_star_resolver_with_stats(fs.clone(), aps.clone(), 3, start_end, &mut stats).unwrap();
+        all_stats.push(("ida_star", stats));
+
+        ara_star_resolver_with_stats(fs.clone(), aps.clone(), 3, start_end, 2.5, 0.5, SearchBudget::MaxExpansions(1000), &mut stats).unwrap();
+        all_stats.push(("ara_star", stats));
+
+        let mut d_star_lite = DStarLite::new(fs.clone(), aps.clone(), 3, start_end).unwrap();
+        d_star_lite.resolve_with_stats(&mut stats).unwrap();
+        all_stats.push(("d_star_lite", stats));
+
+        tsp_resolver_with_stats(fs.clone(), aps.clone(), 3, (start_end.0, vec![start_end.1]), &mut stats).unwrap();
+        all_stats.push(("tsp", stats));
+
+        yen_k_shortest_roads_resolver_with_stats(fs.clone(), aps.clone(), 3, start_end, 3, &mut stats).unwrap();
+        all_stats.push(("yen", stats));
+
+        let penalties = TurnPenalties { move_cost: 1, quarter_turn_cost: 0, half_turn_cost: 0 };
+        a_star_turn_penalty_resolver_with_stats(fs.clone(), aps.clone(), 3, start_end, penalties, None, &mut stats).unwrap();
+        all_stats.push(("turn_penalty", stats));
+
+        timed_a_star_resolver_with_stats(fs.clone(), aps.clone(), 3, start_end, &TimedBord::new(3), &mut stats).unwrap();
+        all_stats.push(("timed_bord", stats));
+
+        cbs_resolver_with_stats(fs.clone(), aps.clone(), 3, vec![start_end], &mut stats).unwrap();
+        all_stats.push(("cbs", stats));
+
+        cooperative_a_star_resolver_with_stats(fs.clone(), aps.clone(), 3, vec![start_end], None, &mut stats).unwrap();
+        all_stats.push(("cooperative", stats));
+
+        for (resolver_name, stats) in all_stats.iter() {
+            assert_eq!(stats.path_cost, Some(6), "{}", resolver_name);
+            assert!(stats.nodes_expanded >= 1, "{}", resolver_name);
+            assert!(stats.nodes_generated >= 1, "{}", resolver_name);
+            assert!(stats.max_open_list_size >= 1, "{}", resolver_name);
+        }
+
+        hpa_star_resolver_with_stats(fs.clone(), aps.clone(), 3, start_end, 2, &mut stats).unwrap();
+
+        assert!(stats.path_cost.unwrap() >= 6);
+        assert!(stats.nodes_expanded >= 1);
+
+        theta_star_resolver_with_stats(fs, aps, 3, start_end, &mut stats).unwrap();
+
+        assert!(stats.path_cost.unwrap() <= 6);
+        assert!(stats.nodes_expanded >= 1);
+    }
+
+    #[test]
+    fn other_resolvers_with_stats_no_road_test() {
+        let (_, fs, aps) = testing_data_no_road();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(1)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point{
+                    x: Some(1),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+        let mut stats = SearchStats::new();
+
+        assert!(jps_resolver_with_stats(fs.clone(), aps.clone(), 2, start_end, Connectivity::Four, &mut stats).is_err());
+        assert_eq!((stats.path_cost, stats.nodes_expanded), (None, 1));
+
+        assert!(ida_star_resolver_with_stats(fs.clone(), aps.clone(), 2, start_end, &mut stats).is_err());
+        assert_eq!((stats.path_cost, stats.nodes_expanded), (None, 0));
+
+        assert!(ara_star_resolver_with_stats(fs.clone(), aps.clone(), 2, start_end, 2.5, 0.5, SearchBudget::MaxExpansions(1000), &mut stats).is_err());
+        assert_eq!((stats.path_cost, stats.nodes_expanded), (None, 1));
+
+        assert!(theta_star_resolver_with_stats(fs.clone(), aps.clone(), 2, start_end, &mut stats).is_err());
+        assert_eq!((stats.path_cost, stats.nodes_expanded), (None, 1));
+
+        assert!(tsp_resolver_with_stats(fs.clone(), aps.clone(), 2, (start_end.0, vec![start_end.1]), &mut stats).is_err());
+        assert_eq!((stats.path_cost, stats.nodes_expanded), (None, 1));
+
+        assert!(yen_k_shortest_roads_resolver_with_stats(fs.clone(), aps.clone(), 2, start_end, 3, &mut stats).is_err());
+        assert_eq!((stats.path_cost, stats.nodes_expanded), (None, 1));
+
+        let mut d_star_lite = DStarLite::new(fs, aps, 2, start_end).unwrap();
+
+        assert!(d_star_lite.resolve_with_stats(&mut stats).is_err());
+        assert_eq!(stats.path_cost, None);
+        assert!(stats.nodes_expanded >= 1);
+    }
+
+    #[test]
+    fn a_star_resolver_with_stats_no_road_test() {
+        let (_, fs, aps) = testing_data_no_road();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(1)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point{
+                    x: Some(1),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+        let mut stats = SearchStats::new();
+
+        assert!(a_star_resolver_with_stats(fs, aps, 2, start_end, &mut stats).is_err());
+        assert_eq!(stats.path_cost, None);
+        assert_eq!(stats.nodes_expanded, 1);
+        assert_eq!(stats.nodes_generated, 0);
+    }
+
+    #[test]
+    fn a_star_resolver_path_test() {
+        let (matrix, fs, aps) = testing_data_heavy_matrix();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(2),
+                    y: Some(0)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+
+        let path = a_star_resolver(fs.clone(), aps.clone(), 3, start_end).unwrap();
+        let mut all_path: Vec<Path> = Vec::new();
+
+        dfs_fs_aps_recursive(fs, aps, start_end, 3, &mut Vec::new(), &mut vec![start_end.0], &mut all_path).unwrap();
+
+        assert_eq!(all_path, vec![path.clone()]);
+        assert_eq!(path.cost, 6);
+        assert!(path.is_valid_on(&matrix));
+        assert_eq!(path.moves().unwrap(), vec![Move::Right, Move::Right, Move::Up, Move::Up, Move::Left, Move::Left]);
+        assert_eq!(path.turns().unwrap(), vec![Turn::Straight, Turn::Left, Turn::Straight, Turn::Left, Turn::Straight]);
+
+        let round_trip = path.clone().concat(path.clone().reverse()).unwrap();
+
+        assert_eq!(round_trip.cost, 12);
+        assert_eq!(round_trip.first(), round_trip.last());
+        assert!(round_trip.is_valid_on(&matrix));
+    }
+
+    fn testing_data_open_bord() -> (Vec<Field>, Vec<u32>, (Field, Field)) {
+        let matrix_size = 20;
+        let mut matrix: Vec<Vec<i8>> = vec![vec![0; matrix_size]; matrix_size];
+
+        matrix[0][0] = 1;
+        matrix[matrix_size - 1][matrix_size - 1] = 2;
+
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point {
+                    x: Some(matrix_size - 1),
+                    y: Some(matrix_size - 1)
+                },
+                value: Some(2)
+            }
+        );
+
+        return (fs, aps, start_end);
+    }
+
+    #[test]
+    fn dfs_all_paths_with_limits_cancel_test() {
+        let (fs, aps, start_end) = testing_data_open_bord();
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let mut limits = SearchLimits::new();
+
+        limits.cancel_flag = Some(cancel_flag.clone());
+
+        let canceller = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            cancel_flag.store(true, Ordering::Relaxed);
+        });
+        let start_time = Instant::now();
+        let limited_search = dfs_all_paths_with_limits(fs, aps, start_end, 20, &limits).unwrap();
+
+        canceller.join().unwrap();
+
+        assert!(start_time.elapsed() < Duration::from_secs(5));
+        assert!(! limited_search.is_complete);
+        assert_eq!(limited_search.stop_reason, Some(StopReason::Cancelled));
+    }
+
+    #[test]
+    fn dfs_all_paths_with_limits_test() {
+        let (fs, aps, start_end) = testing_data_open_bord();
+        let mut limits = SearchLimits::new();
+
+        limits.max_paths = Some(5);
+
+        let limited_search = dfs_all_paths_with_limits(fs.clone(), aps.clone(), start_end, 20, &limits).unwrap();
+
+        assert_eq!(limited_search.result.len(), 5);
+        assert_eq!(limited_search.stop_reason, Some(StopReason::MaxPaths));
+
+        for path in limited_search.result.iter() {
+            assert_eq!(path.first(), Some(&start_end.0.coordinates));
+            assert_eq!(path.last(), Some(&start_end.1.coordinates));
+        }
+
+        let mut limits = SearchLimits::new();
+
+        limits.deadline = Some(Instant::now() + Duration::from_millis(20));
+
+        let limited_search = dfs_all_paths_with_limits(fs, aps, start_end, 20, &limits).unwrap();
+
+        assert_eq!(limited_search.stop_reason, Some(StopReason::Deadline));
+    }
+
+    #[test]
+    fn a_star_resolver_with_limits_test() {
+        let (fs, aps, start_end) = testing_data_open_bord();
+        let mut limits = SearchLimits::new();
+
+        let limited_search = a_star_resolver_with_limits(fs.clone(), aps.clone(), 20, start_end, &limits).unwrap();
+
+        assert!(limited_search.is_complete);
+        assert_eq!(limited_search.result, a_star_resolver(fs.clone(), aps.clone(), 20, start_end).unwrap());
+
+        limits.max_depth = Some(10);
+
+        let limited_search = a_star_resolver_with_limits(fs, aps, 20, start_end, &limits).unwrap();
+
+        assert!(! limited_search.is_complete);
+        assert_eq!(limited_search.stop_reason, Some(StopReason::MaxDepth));
+        assert_eq!(limited_search.result.cost, 10);
+    }
+
+    #[test]
+    fn a_star_resolver_with_limits_dead_end_test() {
+        let matrix = vec![
+            vec![1, 0, 0],
+            vec![-1, -1, -1],
+            vec![2, 0, 0]
+        ];
+        let (fs, aps) = fs_aps_from_matrix(matrix).unwrap();
+        let start_end = (
+            Field { coordinates: Point { x: Some(0), y: Some(0) }, value: Some(1) },
+            Field { coordinates: Point { x: Some(2), y: Some(0) }, value: Some(2) }
+        );
+        let mut limits = SearchLimits::new();
+
+        limits.max_depth = Some(2);
+
+        assert_eq!(a_star_resolver_with_limits(fs, aps, 3, start_end, &limits), Err("It seem that it has no end to this level"));
+    }
+
+    #[test]
+    fn a_star_turn_penalty_resolver_test() {
+        let (matrix, fs, aps) = testing_data_heavy_matrix();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(2),
+                    y: Some(0)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+        let penalties = TurnPenalties { move_cost: 1, quarter_turn_cost: 2, half_turn_cost: 4 };
+
+        let path = a_star_turn_penalty_resolver(fs.clone(), aps.clone(), 3, start_end, penalties, Some(Move::Up)).unwrap();
+
+        assert_eq!(path.points, a_star_resolver(fs, aps, 3, start_end).unwrap().points);
+        assert_eq!(path.cost, 6 + 3 * 2);
+        assert!(path.is_valid_on(&matrix));
+    }
+
+
+    type AgentsStartEnd = Vec<(Field, Field)>;
+
+    fn testing_data_crossing_agents() -> (Vec<Vec<i8>>, Vec<Field>, Vec<u32>, AgentsStartEnd) {
+        let matrix_size = 5;
+        let mut matrix: Vec<Vec<i8>> = vec![vec![0; matrix_size]; matrix_size];
+
+        matrix[1][1] = -1;
+        matrix[1][3] = -1;
+        matrix[3][1] = -1;
+        matrix[3][3] = -1;
+
+        let (fs, aps) = fs_aps_from_matrix(matrix.clone()).unwrap();
+        let field = |x: usize, y: usize| Field {
+            coordinates: Point {
+                x: Some(x),
+                y: Some(y)
+            },
+            value: Some(0)
+        };
+        let start_end_points = vec![
+            (field(2, 0), field(2, 4)),
+            (field(2, 4), field(2, 0)),
+            (field(0, 2), field(4, 2)),
+            (field(4, 2), field(0, 2))
+        ];
+
+        return (matrix, fs, aps, start_end_points);
+    }
+
+    fn assert_collision_free_roads(matrix: &[Vec<i8>], roads: &[Vec<Point>], start_end_points: &[(Field, Field)]) {
+        let get_position = |road: &Vec<Point>, time: usize| road[time.min(road.len() - 1)];
+        let max_time = roads.iter().map(|road| road.len()).max().unwrap();
+
+        assert_eq!(roads.len(), start_end_points.len());
+
+        for (road, (start_point, end_point)) in roads.iter().zip(start_end_points.iter()) {
+            assert_eq!(road.first(), Some(&start_point.coordinates));
+            assert_eq!(road.last(), Some(&end_point.coordinates));
+            assert!(road.iter().all(|point| matrix[point.x.unwrap()][point.y.unwrap()] != -1));
+            assert!(road.windows(2).all(|points| points[0] == points[1] || Move::between(points[0], points[1]).is_ok()));
+        }
+
+        for time in 0..max_time {
+            for first_agent in 0..roads.len() {
+                for second_agent in first_agent + 1..roads.len() {
+                    assert_ne!(get_position(&roads[first_agent], time), get_position(&roads[second_agent], time));
+
+                    if time > 0 {
+                        let is_swap = get_position(&roads[first_agent], time) == get_position(&roads[second_agent], time - 1)
+                            && get_position(&roads[second_agent], time) == get_position(&roads[first_agent], time - 1);
+
+                        assert!(! is_swap);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cbs_resolver_test() {
+        let (matrix, fs, aps, start_end_points) = testing_data_crossing_agents();
+        let matrix_size = matrix.len();
+
+        let roads = cbs_resolver(fs, aps, matrix_size, start_end_points.clone()).unwrap();
+
+        assert_collision_free_roads(&matrix, &roads, &start_end_points);
+        assert_eq!(roads.iter().map(|road| road.len() - 1).sum::<usize>(), 25);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cbs_resolver_same_end_test() {
+        let (_, fs, aps) = testing_data_heavy_matrix();
+        let end_point = Field {
+            coordinates: Point {
+                x: Some(0),
+                y: Some(0)
+            },
+            value: Some(2)
+        };
+        let start_end_points = vec![
+            (Field { coordinates: Point { x: Some(2), y: Some(0) }, value: Some(1) }, end_point),
+            (Field { coordinates: Point { x: Some(2), y: Some(2) }, value: Some(1) }, end_point)
+        ];
+
+        cbs_resolver(fs, aps, 3, start_end_points).unwrap();
+    }
+
+    #[test]
+    fn cooperative_a_star_resolver_test() {
+        let (matrix, fs, aps, start_end_points) = testing_data_crossing_agents();
+        let matrix_size = matrix.len();
+
+        let roads = cooperative_a_star_resolver(fs.clone(), aps.clone(), matrix_size, start_end_points.clone(), None).unwrap();
+
+        assert_collision_free_roads(&matrix, &roads, &start_end_points);
+        assert_eq!(roads[0].len() - 1, 4);
+
+        for window in 2..6 {
+            let windowed_roads = cooperative_a_star_resolver(fs.clone(), aps.clone(), matrix_size, start_end_points.clone(), Some(window)).unwrap();
+
+            assert_collision_free_roads(&matrix, &windowed_roads, &start_end_points);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn cooperative_a_star_resolver_empty_window_test() {
+        let (matrix, fs, aps, start_end_points) = testing_data_crossing_agents();
+
+        cooperative_a_star_resolver(fs, aps, matrix.len(), start_end_points, Some(0)).unwrap();
+    }
+
+    #[test]
+    fn timed_a_star_resolver_test() {
+        let matrix_size = 6;
+        let mut matrix: Vec<Vec<i8>> = vec![vec![0; matrix_size]; matrix_size];
+
+        matrix[0][0] = 1;
+        matrix[matrix_size - 1][matrix_size - 1] = 2;
+
+        for value in matrix[3].iter_mut().skip(1) {
+            *value = -1;
+        }
+
+        let (fs, aps) = fs_aps_from_matrix(matrix.clone()).unwrap();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point {
+                    x: Some(5),
+                    y: Some(5)
+                },
+                value: Some(2)
+            }
+        );
+        let blinking_wall = Point { x: Some(3), y: Some(0) };
+        let patrol_points: Vec<Point> = (0..matrix_size).chain((1..matrix_size - 1).rev()).map(|y| Point { x: Some(4), y: Some(y) }).collect();
+        let mut timed_bord = TimedBord::new(matrix_size);
+
+        timed_bord.add_schedule(blinking_wall, FieldSchedule::Periodic { period: 5, blocked_phases: vec![0, 1, 2, 3] }).unwrap();
+        timed_bord.add_patrol(patrol_points).unwrap();
+
+        let timed_road = timed_a_star_resolver(fs, aps, matrix_size, start_end, &timed_bord).unwrap();
+
+        assert_eq!(timed_road.first().map(|timed_point| timed_point.point), Some(start_end.0.coordinates));
+        assert_eq!(timed_road.last().map(|timed_point| timed_point.point), Some(start_end.1.coordinates));
+        assert!(timed_road.iter().enumerate().all(|(time, timed_point)| timed_point.time == time));
+        assert!(timed_road.iter().skip(1).all(|timed_point| ! timed_bord.is_blocked_at(timed_point.point, timed_point.time)));
+        assert!(timed_road.windows(2).all(|timed_points| timed_points[0].point == timed_points[1].point || Move::between(timed_points[0].point, timed_points[1].point).is_ok()));
+        assert!(timed_road.iter().any(|timed_point| timed_point.point == blinking_wall && timed_point.time % 5 == 4));
+        assert!(timed_road.iter().all(|timed_point| matrix[timed_point.point.x.unwrap()][timed_point.point.y.unwrap()] != -1));
+        assert_eq!(timed_road.len() - 1, 11);
+    }
+
+    #[test]
+    fn hpa_star_resolver_test() {
+        let matrix_size = 20;
+        let mut matrix: Vec<Vec<i8>> = vec![vec![0; matrix_size]; matrix_size];
+
+        for x in (5..matrix_size).step_by(6) {
+            for (y, value) in matrix[x].iter_mut().enumerate() {
+                if (y + x) % 7 != 0 {
+                    *value = -1;
+                }
+            }
+        }
+
+        let (fs, aps) = fs_aps_from_matrix(matrix.clone()).unwrap();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point {
+                    x: Some(19),
+                    y: Some(19)
+                },
+                value: Some(2)
+            }
+        );
+        let mut hierarchical_graph = HierarchicalGraph::new(fs.clone(), aps.clone(), matrix_size, 5).unwrap();
+        let shorter_road = bfs_resolver(fs.clone(), aps.clone(), matrix_size, start_end).unwrap();
+
+        let path = hierarchical_graph.resolve(start_end).unwrap();
+
+        assert!(path.is_valid_on(&matrix));
+        assert_eq!(path.first(), Some(&start_end.0.coordinates));
+        assert_eq!(path.last(), Some(&start_end.1.coordinates));
+        assert!(path.cost >= shorter_road.len() - 1);
+        assert_eq!(hpa_star_resolver(fs, aps, matrix_size, start_end, 5).unwrap(), path);
+
+        for (y, value) in matrix[11].iter_mut().enumerate() {
+            if *value != -1 {
+                *value = -1;
+                hierarchical_graph.update_field(Point { x: Some(11), y: Some(y) }, -1).unwrap();
+            }
+        }
+
+        assert!(hierarchical_graph.resolve(start_end).is_err());
+
+        matrix[11][10] = 0;
+        hierarchical_graph.update_field(Point { x: Some(11), y: Some(10) }, 0).unwrap();
+
+        let path = hierarchical_graph.resolve(start_end).unwrap();
+
+        assert!(path.is_valid_on(&matrix));
+        assert!(path.points.contains(&Point { x: Some(11), y: Some(10) }));
+    }
+
+    #[test]
+    fn distance_table_test() {
+        let (matrix, fs, aps) = testing_data_heavy_matrix();
+        let distance_table = DistanceTable::new(fs.clone(), aps.clone(), 3).unwrap();
+        let file_path = std::env::temp_dir().join("esgi_arena_distance_table_test.bin");
+        let file_path = file_path.to_str().unwrap();
+
+        for from_index in 0..9 {
+            for to_index in 0..9 {
+                let from_point = Point::from_index(from_index, 3).unwrap();
+                let to_point = Point::from_index(to_index, 3).unwrap();
+                let start_end = (
+                    Field { coordinates: from_point, value: Some(1) },
+                    Field { coordinates: to_point, value: Some(2) }
+                );
+
+                match bfs_resolver(fs.clone(), aps.clone(), 3, start_end) {
+                    Ok(road) => {
+                        let path = distance_table.road(from_point, to_point).unwrap();
+
+                        assert_eq!(distance_table.distance(from_point, to_point), Some(road.len() - 1));
+                        assert_eq!(path.cost, road.len() - 1);
+                        assert!(matrix[from_point.x.unwrap()][from_point.y.unwrap()] == -1 || path.is_valid_on(&matrix));
+                    },
+                    Err(_) => assert_eq!(distance_table.distance(from_point, to_point), None)
+                }
+            }
+        }
+
+        distance_table.save(file_path).unwrap();
+
+        assert_eq!(DistanceTable::load(file_path).unwrap(), distance_table);
+
+        std::fs::remove_file(file_path).unwrap();
+
+        assert!(DistanceTable::load(file_path).is_err());
+    }
+
+    #[test]
+    fn a_star_multi_roads_flow_field_test() {
+        let (matrix, fs, aps) = testing_data_heavy_matrix_multi_end();
+        let start_end = (Field {
+            coordinates: Point {
+                x: Some(2),
+                y: Some(0)
+            },
+            value: Some(1)
+        }, vec![Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(2)
+            },
+            Field {
+                coordinates: Point {
+                    x: Some(2),
+                    y: Some(2)
+                },
+                value: Some(2)
+            }
+        ]);
+
+        let flow_field = a_star_multi_roads_flow_field(fs.clone(), aps.clone(), 3, start_end.clone()).unwrap();
+
+        assert_eq!(flow_field.road_from(start_end.0.coordinates).unwrap(), a_star_nearest_road_resolver(fs, aps, 3, start_end).unwrap());
+        assert_eq!(flow_field.direction(Point { x: Some(0), y: Some(1) }), Some(Move::Left));
+
+        for (index, distance) in flow_field.distances().iter().enumerate() {
+            let point = Point::from_index(index, 3).unwrap();
+
+            match distance {
+                Some(distance) => {
+                    let path = flow_field.road_from(point).unwrap();
+
+                    assert_eq!(path.cost, *distance);
+                    assert!(path.is_valid_on(&matrix));
+                },
+                None => assert_eq!(matrix[point.x.unwrap()][point.y.unwrap()], -1)
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "It seem that it has no end to this level")]
+    fn a_star_multi_roads_flow_field_invalid_matrix() {
+        let (_, fs, aps) = testing_data_no_road();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(1)
+                },
+                value: Some(1)
+            },
+            vec![Field {
+                coordinates: Point{
+                    x: Some(1),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }]
+        );
+        a_star_multi_roads_flow_field(fs, aps, 2, start_end).unwrap();
+    }
+
+    #[test]
+    fn validate_path_test() {
+        let (matrix, fs, aps) = testing_data_heavy_matrix();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(2),
+                    y: Some(0)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+        let road = a_star_resolver(fs, aps, 3, start_end).unwrap().points;
+
+        let report = validate_path(&matrix, &road).unwrap();
+
+        assert_eq!(report, PathReport { violation: None, cost: 6, optimal_cost: Some(6), is_optimal: true });
+
+        let mut longer_road = road.clone();
+
+        longer_road.insert(1, Point { x: Some(2), y: Some(1) });
+        longer_road.insert(2, Point { x: Some(2), y: Some(0) });
+
+        let report = validate_path(&matrix, &longer_road).unwrap();
+
+        assert!(report.is_valid());
+        assert_eq!(report.cost, 8);
+        assert!(! report.is_optimal);
+
+        let mut jump_road = road.clone();
+
+        jump_road.remove(3);
+
+        assert_eq!(validate_path(&matrix, &jump_road).unwrap().violation, Some(PathViolation::NotNeighbour { step: 3 }));
+        assert_eq!(validate_path(&matrix, &road[..4]).unwrap().violation, Some(PathViolation::WrongEnd));
+        assert_eq!(validate_path(&matrix, &[road[0], Point { x: Some(3), y: Some(0) }]).unwrap().violation, Some(PathViolation::OutsideBord { step: 1 }));
+        assert_eq!(validate_path(&matrix, &[road[0], Point { x: Some(1), y: Some(0) }, road[6]]).unwrap().violation, Some(PathViolation::Wall { step: 1 }));
+    }
+
+    #[test]
+    #[should_panic(expected = "The bord size cannot be shorter than 2 lignes")]
+    fn validate_path_invalid_matrix() {
+        validate_path(&[vec![1]], &[Point { x: Some(0), y: Some(0) }]).unwrap();
+    }
+
+    #[test]
+    fn a_star_uniform_cost_resolver_test() {
+        let (_, fs, aps) = testing_data_heavy_matrix();
+        let start_end = (
+            Field {
+                coordinates: Point {
+                    x: Some(2),
+                    y: Some(0)
+                },
+                value: Some(1)
+            },
+            Field {
+                coordinates: Point {
+                    x: Some(0),
+                    y: Some(0)
+                },
+                value: Some(2)
+            }
+        );
+        let road = a_star_resolver(fs.clone(), aps.clone(), 3, start_end).unwrap();
+
+        assert_eq!(a_star_uniform_cost_resolver(fs.clone(), aps.clone(), 3, start_end, u64::MAX / 10).unwrap(), (road.clone(), u64::MAX / 10 * 6));
+        assert_eq!(a_star_uniform_cost_resolver(fs.clone(), aps.clone(), 3, start_end, 0.25_f64).unwrap(), (road, 1.5));
+        assert!(a_star_uniform_cost_resolver(fs, aps, 3, start_end, u64::MAX / 5).is_err());
+    }
+}